@@ -48,6 +48,7 @@ fn setup(
                 subdivision: 20,
                 anchor: TextAnchor::Center,
                 justify: JustifyText::Left,
+                ..default()
             },
         },
         material: base_material.clone(),
@@ -65,6 +66,7 @@ fn setup(
                 subdivision: 20,
                 anchor: TextAnchor::Center,
                 justify: JustifyText::Center,
+                ..default()
             },
         },
         material: base_material.clone(),
@@ -82,12 +84,33 @@ fn setup(
                 subdivision: 20,
                 anchor: TextAnchor::Center,
                 justify: JustifyText::Right,
+                ..default()
             },
         },
         material: base_material.clone(),
         transform: Transform::from_xyz(5.0, 3.0, 0.0),
         ..default()
     });
+
+    // Example 4: Fully Justified paragraph wrapped to a fixed width
+    commands.spawn(TextMeshBundle {
+        text_mesh: TextMesh {
+            text: "Justified text spreads the words of each wrapped line across the full width"
+                .to_string(),
+            font: font.clone(),
+            style: TextMeshStyle {
+                depth: 0.1,
+                subdivision: 20,
+                anchor: TextAnchor::Center,
+                justify: JustifyText::Justified,
+                max_width: Some(12.0),
+                ..default()
+            },
+        },
+        material: base_material.clone(),
+        transform: Transform::from_xyz(0.0, -1.5, 0.0),
+        ..default()
+    });
 }
 
 fn rotate_text(time: Res<Time>, mut query: Query<&mut Transform, With<TextMesh>>) {
@@ -1,10 +1,21 @@
+use crate::component::TextMeshStyle;
+use crate::layout::{char_offsets, layout_offset, layout_runs, shape_line, TextRun};
+use crate::outline::{glyph_contours, signed_area};
+use crate::path::PathSampler;
 use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
+use self_cell::self_cell;
+use std::fmt;
+use std::sync::Arc;
 use thiserror::Error;
+use ttf_parser::GlyphId;
 
 /// Asset containing font data for 3D text mesh generation.
 ///
-/// This asset type holds the raw bytes of a TrueType font file.
+/// This asset type holds the raw bytes of a TrueType font file along with tables
+/// parsed once on creation (font metrics, character map and glyph advances), so
+/// metric queries like [`glyph_metrics`](Self::glyph_metrics) are cheap lookups.
 /// Fonts are automatically loaded from the asset server and used by the [`TextMesh`](crate::TextMesh)
 /// component to generate 3D mesh geometry.
 ///
@@ -25,13 +36,119 @@ use thiserror::Error;
 /// # Supported Formats
 ///
 /// - TrueType (`.ttf`)
-///
-/// **Note**: Some OpenType fonts (`.otf`) with TrueType outlines are supported,
-/// but OpenType fonts with CFF/PostScript outlines are not (limitation of ttf-parser).
+/// - OpenType (`.otf`) with TrueType or CFF outlines
 #[derive(Asset, TypePath, Debug)]
 pub struct FontMesh {
-    /// Raw font file data in TTF or OTF format.
-    pub data: Vec<u8>,
+    /// Raw font file data in TTF or OTF format
+    data: Arc<[u8]>,
+    /// Parsed face shared with generation tasks, `None` if the data is not a valid font
+    face: Option<Arc<FontFace>>,
+    /// Pre-parsed tables, `None` if the data is not a valid font
+    tables: Option<FontTables>,
+}
+
+/// Parsed face borrowing from the font data of a [`FontFace`].
+type SharedFace<'a> = rustybuzz::Face<'a>;
+
+self_cell!(
+    /// A font face parsed once for shaping and outline extraction.
+    ///
+    /// Holds the font data it borrows from, so it can be shared with background
+    /// tasks without parsing the font again.
+    pub(crate) struct FontFace {
+        owner: Arc<[u8]>,
+
+        #[covariant]
+        dependent: SharedFace,
+    }
+);
+
+impl FontFace {
+    fn parse(data: Arc<[u8]>) -> Option<Self> {
+        Self::try_new(data, |data| rustybuzz::Face::from_slice(data, 0).ok_or(())).ok()
+    }
+
+    /// The parsed face, which also derefs to the `ttf_parser` face.
+    pub(crate) fn face(&self) -> &rustybuzz::Face<'_> {
+        self.borrow_dependent()
+    }
+}
+
+impl fmt::Debug for FontFace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FontFace")
+            .field("len", &self.borrow_owner().len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Font tables extracted once when a [`FontMesh`] is created.
+#[derive(Debug)]
+struct FontTables {
+    metrics: FontMetrics,
+    /// Unicode character map
+    cmap: HashMap<char, GlyphId>,
+    /// Normalized horizontal advance of each glyph, indexed by glyph ID
+    advances: Vec<f32>,
+    /// Whether each glyph has visible geometry, indexed by glyph ID
+    outlines: Vec<bool>,
+}
+
+impl FontTables {
+    fn parse(face: &fontmesh::Face) -> Self {
+        let scale = 1.0 / face.units_per_em() as f32;
+
+        let ascender = fontmesh::ascender(face);
+        let descender = fontmesh::descender(face);
+        let line_gap = fontmesh::line_gap(face);
+        let metrics = FontMetrics {
+            ascender,
+            descender,
+            line_gap,
+            line_height: ascender - descender + line_gap,
+        };
+
+        // Earlier subtables take precedence, matching `Face::glyph_index`. Glyph IDs
+        // past the glyph count are dropped, as broken fonts may contain them.
+        let glyph_count = face.number_of_glyphs();
+        let mut cmap = HashMap::default();
+        if let Some(table) = face.tables().cmap {
+            for subtable in table.subtables.into_iter().filter(|s| s.is_unicode()) {
+                subtable.codepoints(|codepoint| {
+                    let (Some(character), Some(glyph_id)) =
+                        (char::from_u32(codepoint), subtable.glyph_index(codepoint))
+                    else {
+                        return;
+                    };
+                    if glyph_id.0 >= glyph_count {
+                        return;
+                    }
+                    cmap.entry(character).or_insert(glyph_id);
+                });
+            }
+        }
+
+        let glyph_ids = (0..glyph_count).map(GlyphId);
+        let advances = glyph_ids
+            .clone()
+            .map(|id| face.glyph_hor_advance(id).unwrap_or(0) as f32 * scale)
+            .collect();
+        // Reading `glyf` headers is much cheaper than outlining every glyph
+        let glyf = face.tables().glyf;
+        let outlines = glyph_ids
+            .map(|id| match glyf {
+                Some(glyf) => glyf.bbox(id).is_some(),
+                None => face.glyph_bounding_box(id).is_some(),
+            })
+            .collect();
+
+        Self {
+            metrics,
+            cmap,
+            advances,
+            outlines,
+        }
+    }
 }
 
 /// Metrics for a single glyph
@@ -43,6 +160,25 @@ pub struct GlyphMetrics {
     pub has_outline: bool,
 }
 
+/// A closed, flattened contour of a glyph outline.
+///
+/// Outer contours run counter-clockwise and holes (e.g. the inside of "O")
+/// clockwise, so the filled area is always to the left of the direction of travel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphContour {
+    /// Points of the closed polyline; the last point connects back to the first
+    pub points: Vec<Vec2>,
+    /// Whether the contour is a hole in an outer contour
+    pub is_hole: bool,
+}
+
+impl GlyphContour {
+    fn new(points: Vec<Vec2>) -> Self {
+        let is_hole = signed_area(&points) < 0.0;
+        Self { points, is_hole }
+    }
+}
+
 /// Font-level metrics
 #[derive(Debug, Clone, Copy)]
 pub struct FontMetrics {
@@ -57,6 +193,23 @@ pub struct FontMetrics {
 }
 
 impl FontMesh {
+    /// Create a font asset from raw TTF or OTF data.
+    ///
+    /// The font is parsed once, immediately, and reused by all queries and mesh
+    /// generation. Invalid data still produces an asset, but all metric queries
+    /// return `None` and no meshes are generated.
+    pub fn new(data: Vec<u8>) -> Self {
+        let data: Arc<[u8]> = data.into();
+        let face = FontFace::parse(data.clone()).map(Arc::new);
+        let tables = face.as_ref().map(|face| FontTables::parse(face.face()));
+        Self { data, face, tables }
+    }
+
+    /// Raw font file data in TTF or OTF format.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
     /// Get metrics for a specific character.
     ///
     /// Returns `None` if the character is not in the font.
@@ -75,18 +228,43 @@ impl FontMesh {
     /// # }
     /// ```
     pub fn glyph_metrics(&self, character: char) -> Option<GlyphMetrics> {
-        let face = fontmesh::parse_font(&self.data).ok()?;
-        let advance = fontmesh::glyph_advance(&face, character)?;
-
-        // Check if glyph has outline by trying to generate a mesh
-        let has_outline = fontmesh::char_to_mesh_2d(&face, character, 1).is_ok();
+        let tables = self.tables.as_ref()?;
+        let glyph_id = tables.cmap.get(&character)?;
 
         Some(GlyphMetrics {
-            advance,
-            has_outline,
+            advance: *tables.advances.get(glyph_id.0 as usize)?,
+            has_outline: *tables.outlines.get(glyph_id.0 as usize)?,
         })
     }
 
+    /// Get the flattened outline of a character, in em units.
+    ///
+    /// Each curve is split into `subdivision` line segments, as with
+    /// [`TextMeshStyle::subdivision`]. Returns `None` if the character is not in
+    /// the font or has no outline (e.g. a space).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::FontMesh;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(contours) = font_assets
+    ///     .get(&font_handle)
+    ///     .and_then(|font| font.glyph_contours('O', 10))
+    /// {
+    ///     for contour in contours.iter().filter(|contour| !contour.is_hole) {
+    ///         println!("Outer contour with {} points", contour.points.len());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn glyph_contours(&self, character: char, subdivision: u8) -> Option<Vec<GlyphContour>> {
+        let glyph_id = *self.tables.as_ref()?.cmap.get(&character)?;
+        let contours = glyph_contours(self.face()?, glyph_id, subdivision)?;
+        Some(contours.into_iter().map(GlyphContour::new).collect())
+    }
+
     /// Get font-level metrics (ascender, descender, line height, etc.)
     ///
     /// Returns `None` if the font data is invalid.
@@ -105,23 +283,27 @@ impl FontMesh {
     /// # }
     /// ```
     pub fn font_metrics(&self) -> Option<FontMetrics> {
-        let face = fontmesh::parse_font(&self.data).ok()?;
+        self.tables.as_ref().map(|tables| tables.metrics)
+    }
 
-        let ascender = fontmesh::ascender(&face);
-        let descender = fontmesh::descender(&face);
-        let line_gap = fontmesh::line_gap(&face);
+    /// The parsed font face for shaping and outline extraction.
+    ///
+    /// Returns `None` if the font data is invalid.
+    pub(crate) fn face(&self) -> Option<&rustybuzz::Face<'_>> {
+        self.face.as_deref().map(FontFace::face)
+    }
 
-        Some(FontMetrics {
-            ascender,
-            descender,
-            line_gap,
-            line_height: ascender - descender + line_gap,
-        })
+    /// Shared handle to the parsed face for use in background tasks.
+    ///
+    /// Returns `None` if the font data is invalid.
+    pub(crate) fn shared_face(&self) -> Option<Arc<FontFace>> {
+        self.face.clone()
     }
 
     /// Calculate the width of a text string.
     ///
-    /// This sums the advance widths of all characters. Does not account for kerning.
+    /// The text is shaped as [`TextMesh`](crate::TextMesh) does with the default
+    /// [`TextMeshStyle`], so ligatures and kerning are taken into account.
     ///
     /// # Example
     ///
@@ -136,29 +318,31 @@ impl FontMesh {
     /// # }
     /// ```
     pub fn text_width(&self, text: &str) -> f32 {
-        let face = match fontmesh::parse_font(&self.data) {
-            Ok(f) => f,
-            Err(_) => return 0.0,
+        self.text_width_with_style(text, &TextMeshStyle::default())
+    }
+
+    /// Calculate the width of a text string laid out with the given style.
+    ///
+    /// Use this to measure text as it will be rendered by a [`TextMesh`](crate::TextMesh)
+    /// with the same style, e.g. with kerning disabled or a different
+    /// [`font_size`](TextMeshStyle::font_size). The result is in world units,
+    /// measured down the column for vertical [`writing_mode`](TextMeshStyle::writing_mode)s.
+    ///
+    /// Only this font is used: [`fallback_fonts`](TextMeshStyle::fallback_fonts) are
+    /// not applied, so characters this font lacks are measured as missing glyphs.
+    pub fn text_width_with_style(&self, text: &str, style: &TextMeshStyle) -> f32 {
+        let Some(face) = self.face() else {
+            return 0.0;
         };
 
-        text.chars()
-            .map(|ch| {
-                fontmesh::glyph_advance(&face, ch).unwrap_or_else(|| {
-                    if ch.is_whitespace() {
-                        // Use font metrics for a proportional fallback space width
-                        (fontmesh::ascender(&face) - fontmesh::descender(&face)) * 0.25
-                    } else {
-                        0.0
-                    }
-                })
-            })
-            .sum()
+        shape_line(face, text, style).width
     }
 
     /// Get character positions for a line of text.
     ///
     /// Returns a vector of (char_index, x_position) pairs for each character.
-    /// Useful for cursor positioning in text editors.
+    /// Useful for cursor positioning in text editors. Characters merged into a
+    /// single glyph by shaping share that glyph's advance evenly.
     ///
     /// # Example
     ///
@@ -175,25 +359,103 @@ impl FontMesh {
     /// # }
     /// ```
     pub fn char_positions(&self, text: &str) -> Vec<(usize, f32)> {
-        let face = match fontmesh::parse_font(&self.data) {
-            Ok(f) => f,
-            Err(_) => return Vec::new(),
+        self.char_positions_with_style(text, &TextMeshStyle::default())
+    }
+
+    /// Get character positions for a line of text laid out with the given style.
+    ///
+    /// Positions are in world units, scaled by the style's
+    /// [`font_size`](TextMeshStyle::font_size). As with
+    /// [`text_width_with_style`](Self::text_width_with_style), fallback fonts are
+    /// not applied, so characters this font lacks are measured as missing glyphs.
+    pub fn char_positions_with_style(
+        &self,
+        text: &str,
+        style: &TextMeshStyle,
+    ) -> Vec<(usize, f32)> {
+        let Some(face) = self.face() else {
+            return Vec::new();
         };
 
-        text.chars()
+        char_offsets(face, text, style)
+            .into_iter()
             .enumerate()
-            .scan(0.0, |x, (idx, ch)| {
-                let current_x = *x;
-                *x += fontmesh::glyph_advance(&face, ch).unwrap_or_else(|| {
-                    if ch.is_whitespace() {
-                        // Use font metrics for a proportional fallback space width
-                        (fontmesh::ascender(&face) - fontmesh::descender(&face)) * 0.25
-                    } else {
-                        0.0
-                    }
-                });
-                Some((idx, current_x))
+            .collect()
+    }
+
+    /// Get the flattened outlines of a text laid out with the given style.
+    ///
+    /// Returns `(char_index, contour)` pairs in world units, positioned like the
+    /// front face of a [`TextMesh`](crate::TextMesh) with the same style: shaped,
+    /// wrapped, justified, anchored and placed along the style's
+    /// [`path`](TextMeshStyle::path). Useful for line renderers, particle emitters
+    /// along letters or collision shapes. [`fill`](TextMeshStyle::fill),
+    /// [`deform`](TextMeshStyle::deform) and fallback fonts are not applied.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::prelude::*;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// let style = TextMeshStyle {
+    ///     anchor: TextAnchor::Center,
+    ///     ..default()
+    /// };
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     for (char_index, contour) in font.text_contours("Hello", &style) {
+    ///         println!("Char {} has a contour of {} points", char_index, contour.points.len());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn text_contours(&self, text: &str, style: &TextMeshStyle) -> Vec<(usize, GlyphContour)> {
+        let Some(face) = self.face() else {
+            return Vec::new();
+        };
+        let faces = [face];
+        let runs = [TextRun {
+            faces: &faces,
+            text,
+            style,
+        }];
+
+        let mut contours: Vec<(usize, Vec<Vec2>)> = layout_runs(&runs, style)
+            .glyphs
+            .iter()
+            .filter_map(|glyph| {
+                let contours = glyph_contours(face, glyph.glyph_id, style.subdivision)?;
+                Some(contours.into_iter().map(|contour| {
+                    let points = contour
+                        .into_iter()
+                        .map(|point| point * style.font_size + glyph.position)
+                        .collect();
+                    (glyph.char_index, points)
+                }))
             })
+            .flatten()
+            .collect();
+
+        // Anchor by the bounds of all contours, like the mesh systems
+        let (min, max) = contours.iter().flat_map(|(_, points)| points).fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), point| (min.min(*point), max.max(*point)),
+        );
+        if min.x <= max.x {
+            let path = style.path.as_ref().and_then(PathSampler::new);
+            let offset =
+                layout_offset(style, path.as_ref(), min.extend(0.0), max.extend(0.0)).truncate();
+            for point in contours.iter_mut().flat_map(|(_, points)| points) {
+                *point += offset;
+                if let Some(path) = &path {
+                    *point = path.map_point(*point);
+                }
+            }
+        }
+
+        contours
+            .into_iter()
+            .map(|(char_index, points)| (char_index, GlyphContour::new(points)))
             .collect()
     }
 }
@@ -228,7 +490,7 @@ impl AssetLoader for FontMeshLoader {
     ) -> Result<Self::Asset, Self::Error> {
         let mut data = Vec::new();
         reader.read_to_end(&mut data).await?;
-        Ok(FontMesh { data })
+        Ok(FontMesh::new(data))
     }
 
     fn extensions(&self) -> &[&str] {
@@ -0,0 +1,238 @@
+//! Glyph geometry cache shared by the mesh systems.
+
+use crate::component::{TextFill, TextMeshStyle};
+use crate::outline::{glyph_geometry, GlyphGeometry};
+use crate::FontMesh;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use ttf_parser::GlyphId;
+
+/// Default maximum number of cached glyphs.
+const DEFAULT_MAX_ENTRIES: usize = 4096;
+
+/// A font asset as of the cache generation its data was read in.
+///
+/// [`GlyphMeshCache::evict_font`] starts a new generation, so geometry generated
+/// from font data read before a reload is never cached for the new data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct CachedFont {
+    pub id: AssetId<FontMesh>,
+    pub generation: u64,
+}
+
+/// Everything that affects the geometry of a single glyph.
+///
+/// Layout-only options (anchor, justification, wrapping) and UV mapping are
+/// applied after geometry generation and are not part of the key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct GlyphCacheKey {
+    font: CachedFont,
+    glyph_id: u16,
+    depth: u32,
+    subdivision: u8,
+    font_size: u32,
+    extrusion: u8,
+    stroke: Option<u32>,
+    bevel: Option<(u32, u32, u8)>,
+}
+
+impl GlyphCacheKey {
+    fn new(font: CachedFont, glyph_id: GlyphId, style: &TextMeshStyle) -> Self {
+        Self {
+            font,
+            glyph_id: glyph_id.0,
+            depth: style.depth.to_bits(),
+            subdivision: style.subdivision,
+            font_size: style.font_size.to_bits(),
+            extrusion: style.extrusion as u8,
+            stroke: match style.fill {
+                TextFill::Solid => None,
+                TextFill::Stroke { width } => Some(width.to_bits()),
+            },
+            bevel: style
+                .bevel
+                .map(|bevel| (bevel.width.to_bits(), bevel.depth.to_bits(), bevel.segments)),
+        }
+    }
+}
+
+struct CacheEntry {
+    /// `None` for glyphs without an outline, so misses are cached too
+    geometry: Option<GlyphGeometry>,
+    last_used: u64,
+}
+
+/// Cache of triangulated glyph geometry shared by all text entities.
+///
+/// Both [`TextMesh`](crate::TextMesh) and [`TextMeshGlyphs`](crate::TextMeshGlyphs)
+/// consult this cache, so a glyph is only tessellated once per font and geometry
+/// style. Entries for a font are dropped when the font asset is modified or
+/// removed, and the least recently used entries are evicted once the cache grows
+/// beyond [`max_entries`](Self::max_entries).
+///
+/// The cache is internally synchronized so background generation tasks can use
+/// it too; clones share the same storage.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::GlyphMeshCache;
+/// // Allow more glyphs for scenes with many fonts and styles
+/// let mut app = App::new();
+/// app.insert_resource(GlyphMeshCache::new(16_384));
+/// ```
+#[derive(Resource, Clone)]
+pub struct GlyphMeshCache {
+    inner: Arc<Mutex<CacheInner>>,
+}
+
+struct CacheInner {
+    entries: HashMap<GlyphCacheKey, CacheEntry>,
+    /// Current generation of each evicted font; fonts never evicted are at `0`
+    generations: HashMap<AssetId<FontMesh>, u64>,
+    max_entries: usize,
+    tick: u64,
+}
+
+impl CacheInner {
+    /// Evict the least recently used entries until at most `len` remain.
+    fn trim(&mut self, len: usize) {
+        if self.entries.len() <= len {
+            return;
+        }
+
+        let mut ticks: Vec<u64> = self.entries.values().map(|e| e.last_used).collect();
+        ticks.sort_unstable();
+        let cutoff = ticks[ticks.len() - len - 1];
+        self.entries.retain(|_, entry| entry.last_used > cutoff);
+    }
+}
+
+impl Default for GlyphMeshCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ENTRIES)
+    }
+}
+
+impl GlyphMeshCache {
+    /// Create an empty cache holding at most `max_entries` glyphs.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(CacheInner {
+                entries: HashMap::default(),
+                generations: HashMap::default(),
+                max_entries,
+                tick: 0,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheInner> {
+        // Entries are always left consistent, so a panicking holder is harmless
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Maximum number of glyphs kept in the cache.
+    pub fn max_entries(&self) -> usize {
+        self.lock().max_entries
+    }
+
+    /// Change the maximum number of cached glyphs, evicting entries if needed.
+    pub fn set_max_entries(&self, max_entries: usize) {
+        let mut inner = self.lock();
+        inner.max_entries = max_entries;
+        inner.trim(max_entries);
+    }
+
+    /// Number of cached glyphs.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Remove all cached glyphs.
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// Remove all cached glyphs of a font.
+    ///
+    /// Geometry still being generated from the font's previous data is not cached.
+    pub fn evict_font(&self, font: AssetId<FontMesh>) {
+        let mut inner = self.lock();
+        *inner.generations.entry(font).or_default() += 1;
+        inner.entries.retain(|key, _| key.font.id != font);
+    }
+
+    /// The font at its current generation, to be read together with its data.
+    pub(crate) fn font(&self, id: AssetId<FontMesh>) -> CachedFont {
+        let generation = self.lock().generations.get(&id).copied().unwrap_or(0);
+        CachedFont { id, generation }
+    }
+
+    /// Get the geometry of a glyph, generating and caching it on a miss.
+    ///
+    /// Generation happens without holding the lock, so concurrent misses on the
+    /// same glyph may both tessellate it. Geometry of a font generation that was
+    /// evicted in the meantime is returned but not cached.
+    pub(crate) fn glyph_geometry(
+        &self,
+        font: CachedFont,
+        face: &fontmesh::Face,
+        glyph_id: GlyphId,
+        style: &TextMeshStyle,
+    ) -> Option<GlyphGeometry> {
+        let key = GlyphCacheKey::new(font, glyph_id, style);
+
+        {
+            let mut inner = self.lock();
+            inner.tick += 1;
+            let tick = inner.tick;
+            if let Some(entry) = inner.entries.get_mut(&key) {
+                entry.last_used = tick;
+                return entry.geometry.clone();
+            }
+        }
+
+        let geometry = glyph_geometry(face, glyph_id, style);
+
+        let mut inner = self.lock();
+        let max_entries = inner.max_entries;
+        let current = inner.generations.get(&font.id).copied().unwrap_or(0);
+        if max_entries > 0 && font.generation == current {
+            if inner.entries.len() >= max_entries {
+                // Evict in batches so a full cache doesn't sort on every miss
+                inner.trim(max_entries * 3 / 4);
+            }
+            inner.tick += 1;
+            let last_used = inner.tick;
+            inner.entries.insert(
+                key,
+                CacheEntry {
+                    geometry: geometry.clone(),
+                    last_used,
+                },
+            );
+        }
+        geometry
+    }
+}
+
+/// System that drops cached glyphs of fonts that were modified or removed.
+pub(crate) fn evict_glyph_mesh_cache(
+    mut events: MessageReader<AssetEvent<FontMesh>>,
+    cache: Res<GlyphMeshCache>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Modified { id } | AssetEvent::Removed { id } => cache.evict_font(*id),
+            _ => {}
+        }
+    }
+}
@@ -47,6 +47,25 @@ pub enum TextAnchor {
     Custom(Vec2),
 }
 
+impl TextAnchor {
+    /// The anchor as a point of the text bounds, where (0,0) is the bottom left
+    /// and (1,1) the top right.
+    pub(crate) fn pivot(self) -> Vec2 {
+        match self {
+            TextAnchor::TopLeft => Vec2::new(0.0, 1.0),
+            TextAnchor::TopCenter => Vec2::new(0.5, 1.0),
+            TextAnchor::TopRight => Vec2::new(1.0, 1.0),
+            TextAnchor::CenterLeft => Vec2::new(0.0, 0.5),
+            TextAnchor::Center => Vec2::new(0.5, 0.5),
+            TextAnchor::CenterRight => Vec2::new(1.0, 0.5),
+            TextAnchor::BottomLeft => Vec2::new(0.0, 0.0),
+            TextAnchor::BottomCenter => Vec2::new(0.5, 0.0),
+            TextAnchor::BottomRight => Vec2::new(1.0, 0.0),
+            TextAnchor::Custom(pivot) => pivot,
+        }
+    }
+}
+
 /// Component for generating 3D text meshes from fonts.
 ///
 /// When added to an entity, this component triggers automatic generation of a 3D mesh
@@ -68,6 +87,7 @@ pub enum TextAnchor {
 ///             subdivision: 25,
 ///             anchor: TextAnchor::Center,
 ///             justify: JustifyText::Center,
+///             ..default()
 ///         },
 ///     },
 ///     ..default()
@@ -131,6 +151,19 @@ pub struct TextMesh {
 ///     justify: JustifyText::Right,
 ///     ..default()
 /// };
+///
+/// // Aligned to where reading starts: left for English, right for Hebrew or Arabic
+/// let style = TextMeshStyle {
+///     justify: JustifyText::Start,
+///     ..default()
+/// };
+///
+/// // Paragraph text filling a fixed width
+/// let style = TextMeshStyle {
+///     justify: JustifyText::Justified,
+///     max_width: Some(10.0),
+///     ..default()
+/// };
 /// ```
 #[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum JustifyText {
@@ -141,12 +174,396 @@ pub enum JustifyText {
     Center,
     /// Align text to the right edge.
     Right,
+    /// Stretch lines to full width by widening the gaps between words.
+    ///
+    /// Lines wrapped at [`TextMeshStyle::max_width`] are stretched to fill it, and the
+    /// last line of each paragraph is aligned to its start. Text that does not wrap
+    /// (without `max_width`, or with [`TextWrap::NoWrap`]) is aligned to its start.
+    Justified,
+    /// Align each paragraph to the edge where it starts: left for left-to-right
+    /// paragraphs, right for right-to-left ones.
+    Start,
+    /// Align each paragraph to the edge where it ends: right for left-to-right
+    /// paragraphs, left for right-to-left ones.
+    End,
+}
+
+/// Direction in which characters and lines flow.
+///
+/// In vertical modes, characters stack top to bottom in columns, using the font's
+/// vertical metrics (`vhea`/`vmtx`/`VORG`) where available and vertical
+/// alternates (e.g. for CJK punctuation). Alignment applies along the column:
+/// [`JustifyText::Left`] and [`JustifyText::Start`] align to the top,
+/// [`JustifyText::Right`] and [`JustifyText::End`] to the bottom.
+/// [`TextMeshStyle::max_width`] limits the column height. Bidirectional
+/// reordering only applies to horizontal text.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Japanese signage: columns read top to bottom, right to left
+/// let style = TextMeshStyle {
+///     writing_mode: WritingMode::VerticalRightToLeft,
+///     max_width: Some(6.0),
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WritingMode {
+    /// Horizontal lines stacked top to bottom.
+    #[default]
+    Horizontal,
+    /// Vertical columns placed from right to left (e.g. Japanese, Chinese).
+    VerticalRightToLeft,
+    /// Vertical columns placed from left to right (e.g. Mongolian).
+    VerticalLeftToRight,
+}
+
+impl WritingMode {
+    /// Whether characters stack vertically.
+    pub fn is_vertical(self) -> bool {
+        self != Self::Horizontal
+    }
+}
+
+/// Base direction of paragraphs in bidirectional text.
+///
+/// The base direction decides the order of mixed left-to-right and right-to-left
+/// runs within a line, and where [`JustifyText::Start`] and [`JustifyText::End`]
+/// align. Characters within a run are always displayed in their natural direction.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Detect each paragraph's direction from its first strong character.
+    #[default]
+    Auto,
+    /// Lay out every paragraph left to right.
+    LeftToRight,
+    /// Lay out every paragraph right to left.
+    RightToLeft,
+}
+
+/// Controls where lines are broken when text exceeds [`TextMeshStyle::max_width`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Reflow a dialogue panel at word boundaries
+/// let style = TextMeshStyle {
+///     max_width: Some(8.0),
+///     wrap: TextWrap::Word,
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextWrap {
+    /// Break lines at word boundaries (Unicode line breaking rules).
+    ///
+    /// Words longer than the maximum width overflow on their own line.
+    #[default]
+    Word,
+    /// Break lines between any two characters.
+    Character,
+    /// Never break lines automatically; only `\n` starts a new line.
+    NoWrap,
+}
+
+/// A curve that text follows instead of a straight baseline.
+///
+/// The text is laid out as usual and then mapped onto the path: distances along
+/// the text become distances along the path, and heights become offsets to the
+/// left of its direction of travel, so text reads upright along the path.
+/// [`TextMesh`] and [`TextMeshSpans`] bend their mesh vertices, so glyphs curve
+/// with the path; [`TextMeshGlyphs`] rotate each rigid glyph child to the path.
+///
+/// The [`anchor`](TextMeshStyle::anchor) places the text on the path. Its
+/// horizontal part picks where along the path the text sits (start, middle or
+/// end), and its vertical part which part of the text lies on the path. Text
+/// that runs past either end continues along the path's end tangent.
+///
+/// Paths are in the entity's local XY plane, in world units. They suit single
+/// lines of horizontal text.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::f32::consts::PI;
+/// // Ring text over the top of a logo, centered above it
+/// let style = TextMeshStyle {
+///     anchor: TextAnchor::BottomCenter,
+///     path: Some(TextMeshPath::Arc {
+///         radius: 2.0,
+///         start_angle: PI,
+///         end_angle: 0.0,
+///     }),
+///     ..default()
+/// };
+///
+/// // Ribbon through a few points
+/// let style = TextMeshStyle {
+///     anchor: TextAnchor::CenterLeft,
+///     path: Some(TextMeshPath::CatmullRom(vec![
+///         Vec2::new(0.0, 0.0),
+///         Vec2::new(2.0, 0.5),
+///         Vec2::new(4.0, -0.5),
+///         Vec2::new(6.0, 0.0),
+///     ])),
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub enum TextMeshPath {
+    /// Circular arc around the origin, from `start_angle` to `end_angle`.
+    ///
+    /// Angles are in radians, counter-clockwise from the +X axis. Going from a
+    /// larger to a smaller angle runs clockwise, which puts text on the outside
+    /// of the circle; counter-clockwise arcs put it on the inside.
+    Arc {
+        /// Radius of the circle
+        radius: f32,
+        /// Angle where the path starts
+        start_angle: f32,
+        /// Angle where the path ends
+        end_angle: f32,
+    },
+    /// Connected cubic Bezier curves, each given as `[start, control, control, end]`.
+    Bezier(Vec<[Vec2; 4]>),
+    /// Catmull-Rom spline passing smoothly through every point.
+    CatmullRom(Vec<Vec2>),
+    /// Straight segments between the points.
+    Polyline(Vec<Vec2>),
+}
+
+/// A vertex modifier that deforms the combined mesh of a text after layout.
+///
+/// Modifiers in [`TextMeshStyle::deform`] are applied in order to [`TextMesh`]
+/// and [`TextMeshSpans`] meshes, after anchoring and any
+/// [`path`](TextMeshStyle::path). They work in the entity's local space, so the
+/// [`anchor`](TextMeshStyle::anchor) picks the axis the text bends, twists or
+/// tapers around. Normals follow the deformed surface. The meshes of
+/// [`TextMeshGlyphs`] children are rigid and not deformed.
+///
+/// Strong deformations stretch long, flat triangles; raise
+/// [`subdivision`](TextMeshStyle::subdivision) if curved outlines look faceted.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Title on a curved screen, bending toward the viewer
+/// let style = TextMeshStyle {
+///     anchor: TextAnchor::Center,
+///     deform: vec![TextDeform::Bend { radius: -6.0 }],
+///     ..default()
+/// };
+///
+/// // Wavy arcade logo; animate `phase` to make it ripple
+/// let style = TextMeshStyle {
+///     anchor: TextAnchor::Center,
+///     deform: vec![
+///         TextDeform::Wave {
+///             amplitude: 0.15,
+///             wavelength: 2.0,
+///             phase: 0.0,
+///         },
+///         TextDeform::Taper {
+///             start: 0.8,
+///             end: 1.2,
+///         },
+///     ],
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub enum TextDeform {
+    /// Wrap the text around a vertical cylinder.
+    ///
+    /// The text plane touches the cylinder at `x = 0`. A positive radius bends
+    /// the text away from the viewer (around a column), a negative radius
+    /// toward the viewer (inside a curved screen).
+    Bend {
+        /// Radius of the cylinder in world units
+        radius: f32,
+    },
+    /// Rotate the text around the X axis, increasingly along it.
+    Twist {
+        /// Rotation in radians per world unit along X
+        angle: f32,
+    },
+    /// Scale the text's height linearly from its left to its right edge.
+    Taper {
+        /// Height scale at the left edge
+        start: f32,
+        /// Height scale at the right edge
+        end: f32,
+    },
+    /// Offset the text vertically along a sine wave.
+    Wave {
+        /// Height of the wave crests in world units
+        amplitude: f32,
+        /// Distance between crests in world units
+        wavelength: f32,
+        /// Offset of the wave in radians
+        phase: f32,
+    },
+}
+
+/// Whether glyphs are extruded into 3D solids or kept as flat faces.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Floor marking seen from above only
+/// let style = TextMeshStyle {
+///     extrusion: TextExtrusion::Flat,
+///     ..default()
+/// };
+///
+/// // Banner readable from both sides
+/// let style = TextMeshStyle {
+///     extrusion: TextExtrusion::FlatDoubleSided,
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextExtrusion {
+    /// Front face, back face and side walls, [`depth`](TextMeshStyle::depth) apart.
+    #[default]
+    Extruded,
+    /// Only the front face at `z = 0`, facing +Z.
+    ///
+    /// Ignores [`depth`](TextMeshStyle::depth) and
+    /// [`bevel`](TextMeshStyle::bevel). About half the vertices of extruded
+    /// text, for decals, floor markings or `Mesh2d`.
+    Flat,
+    /// The front face plus a back face at the same position, facing -Z.
+    ///
+    /// Visible from both sides without disabling backface culling.
+    FlatDoubleSided,
+}
+
+/// How glyph outlines are filled before extrusion.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Neon sign tubes: thin rounded strokes
+/// let style = TextMeshStyle {
+///     fill: TextFill::Stroke { width: 0.05 },
+///     depth: 0.05,
+///     bevel: Some(TextBevel {
+///         width: 0.02,
+///         depth: 0.02,
+///         segments: 4,
+///     }),
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextFill {
+    /// Filled glyphs.
+    #[default]
+    Solid,
+    /// Hollow outlines: a band centered on every contour of the glyph.
+    ///
+    /// The band is extruded, bevelled and UV mapped like a filled glyph. Widths
+    /// above the font's stroke width make inner and outer edges overlap.
+    Stroke {
+        /// Width of the band in world units, half inside and half outside the outline
+        width: f32,
+    },
+}
+
+/// Bevel applied to the front and back edges of extruded glyphs.
+///
+/// The front and back faces are inset by `width` and joined to the side walls by
+/// a strip that is `depth` deep. A single segment produces a flat chamfer; more
+/// segments approximate a rounded (quarter-circle) edge.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Rounded title text
+/// let style = TextMeshStyle {
+///     depth: 0.3,
+///     bevel: Some(TextBevel {
+///         width: 0.02,
+///         depth: 0.03,
+///         segments: 4,
+///     }),
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct TextBevel {
+    /// How far the bevel reaches into the glyph outline.
+    ///
+    /// Keep this well below half the stroke width of the font, or thin strokes
+    /// will fold over themselves.
+    pub width: f32,
+    /// How far the bevel reaches along the extrusion, clamped to half the text depth.
+    pub depth: f32,
+    /// Number of segments in the bevel profile (`1` for a flat chamfer).
+    pub segments: u8,
+}
+
+impl Default for TextBevel {
+    fn default() -> Self {
+        Self {
+            width: 0.02,
+            depth: 0.02,
+            segments: 1,
+        }
+    }
+}
+
+/// Controls how texture coordinates (`ATTRIBUTE_UV_0`) are generated.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Stretch one gradient texture across the whole text
+/// let style = TextMeshStyle {
+///     uv_mapping: TextUvMapping::Text,
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextUvMapping {
+    /// Planar front projection normalized to each glyph's bounding box.
+    ///
+    /// Every glyph shows the full texture.
+    #[default]
+    Glyph,
+    /// Planar front projection normalized to the bounds of the whole text.
+    ///
+    /// A single texture spans all glyphs, e.g. for gradients.
+    Text,
+    /// Front and back faces use planar coordinates in world units, while side walls
+    /// are unwrapped with U along the outline length and V along the depth.
+    ///
+    /// Textures tile rather than stretch, which suits brushed metal or normal maps.
+    Unwrap,
 }
 
 /// Visual styling parameters for generated text meshes.
 ///
-/// Controls the 3D extrusion depth, curve smoothness, positioning, and alignment
-/// of the generated mesh geometry.
+/// Controls the 3D extrusion depth, curve smoothness, positioning, alignment
+/// and line wrapping of the generated mesh geometry.
 ///
 /// # Examples
 ///
@@ -155,7 +572,7 @@ pub enum JustifyText {
 /// # use bevy::prelude::default;
 /// // Flat 2D-style text
 /// let flat = TextMeshStyle {
-///     depth: 0.0,
+///     extrusion: TextExtrusion::Flat,
 ///     subdivision: 15,
 ///     ..default()
 /// };
@@ -166,6 +583,7 @@ pub enum JustifyText {
 ///     subdivision: 30,
 ///     anchor: TextAnchor::Center,
 ///     justify: JustifyText::Center,
+///     ..default()
 /// };
 ///
 /// // Low-poly stylized text
@@ -179,13 +597,26 @@ pub enum JustifyText {
 pub struct TextMeshStyle {
     /// Extrusion depth of the 3D mesh.
     ///
-    /// Controls how far the text is extruded in the Z direction. A value of `0.0`
-    /// produces flat, 2D-style text. Higher values create more pronounced 3D geometry.
-    /// The depth is measured in font units (typically relative to the font's em height).
+    /// Controls how far the text is extruded in the Z direction. Higher values create
+    /// more pronounced 3D geometry. For flat, 2D-style text use
+    /// [`TextExtrusion::Flat`] rather than a depth of `0.0`, which still generates
+    /// side walls and a back face.
+    /// The depth is measured in world units and is independent of [`font_size`](Self::font_size).
     ///
     /// Recommended range: `0.0` to `2.0`.
     pub depth: f32,
 
+    /// Size of the text in world units per em.
+    ///
+    /// Scales glyph outlines and layout without affecting [`depth`](Self::depth).
+    /// A value of `1.0` (the default) keeps the font's normalized em units.
+    pub font_size: f32,
+
+    /// Multiplier applied to the font's line height between lines.
+    ///
+    /// Default: `1.0`.
+    pub line_spacing: f32,
+
     /// Number of segments used to approximate curved glyph outlines.
     ///
     /// Higher values produce smoother curves but increase vertex count and memory usage.
@@ -206,15 +637,124 @@ pub struct TextMeshStyle {
     /// Controls how multiple lines of text are aligned relative to each other.
     /// Has no effect on single-line text. See [`JustifyText`] for options.
     pub justify: JustifyText,
+
+    /// Base direction of each paragraph. See [`TextDirection`].
+    ///
+    /// Default: [`TextDirection::Auto`].
+    pub direction: TextDirection,
+
+    /// Horizontal lines or vertical columns. See [`WritingMode`].
+    ///
+    /// Default: [`WritingMode::Horizontal`].
+    pub writing_mode: WritingMode,
+
+    /// Whether to apply the font's kerning when positioning glyphs.
+    ///
+    /// When enabled, pair adjustments from the font's `GPOS` or `kern` table
+    /// tighten pairs like "AV" or "To". Disable for strict advance-only layout.
+    /// Default: `true`.
+    pub kerning: bool,
+
+    /// Extra space added after each character, in world units.
+    ///
+    /// Positive values space letters out (e.g. stencil text), negative values
+    /// tighten them (e.g. display titles). Default: `0.0`.
+    pub letter_spacing: f32,
+
+    /// Extra space added after each whitespace character, in world units.
+    ///
+    /// Applied on top of [`letter_spacing`](Self::letter_spacing). Default: `0.0`.
+    pub word_spacing: f32,
+
+    /// Maximum line width before text wraps onto a new line.
+    ///
+    /// `None` (the default) disables automatic wrapping. Measured in world
+    /// units, like the generated mesh. In vertical writing modes this is the
+    /// maximum column height.
+    pub max_width: Option<f32>,
+
+    /// How lines are broken when they exceed [`max_width`](Self::max_width).
+    ///
+    /// See [`TextWrap`] for options. Default: [`TextWrap::Word`].
+    pub wrap: TextWrap,
+
+    /// Optional curve the text follows. See [`TextMeshPath`].
+    ///
+    /// Default: `None` (straight lines).
+    pub path: Option<TextMeshPath>,
+
+    /// Modifiers applied in order to the combined mesh. See [`TextDeform`].
+    ///
+    /// Default: empty.
+    pub deform: Vec<TextDeform>,
+
+    /// Whether glyphs are extruded or flat. See [`TextExtrusion`].
+    ///
+    /// Default: [`TextExtrusion::Extruded`].
+    pub extrusion: TextExtrusion,
+
+    /// Whether glyphs are filled or drawn as outlines. See [`TextFill`].
+    ///
+    /// Default: [`TextFill::Solid`].
+    pub fill: TextFill,
+
+    /// Optional bevel on the front and back edges of the extrusion.
+    ///
+    /// `None` (the default) produces hard-edged glyphs. See [`TextBevel`].
+    pub bevel: Option<TextBevel>,
+
+    /// How texture coordinates are generated. See [`TextUvMapping`].
+    pub uv_mapping: TextUvMapping,
+
+    /// Whether to generate `ATTRIBUTE_TANGENT` from the UVs.
+    ///
+    /// Required for normal maps and parallax mapping on `StandardMaterial`.
+    /// Off by default since it adds generation time and vertex memory.
+    pub generate_tangents: bool,
+
+    /// Fonts consulted, in order, for characters missing from the primary font.
+    ///
+    /// Useful to cover symbols or CJK text the primary font lacks. Text is
+    /// generated once all fallback fonts are loaded; fallbacks that fail to load
+    /// are skipped with a warning. Fallback glyphs use the fallback font's
+    /// outlines and metrics at the same [`font_size`](Self::font_size).
+    /// Default: empty.
+    pub fallback_fonts: Vec<Handle<FontMesh>>,
+
+    /// Whether to render characters no font has a glyph for.
+    ///
+    /// When enabled, missing characters are drawn with the font's `.notdef`
+    /// glyph (or a hollow box if the font doesn't draw one) and take up its
+    /// advance. When disabled (the default), they are skipped with no advance.
+    /// Either way, a [`MissingGlyphs`](crate::MissingGlyphs) message lists them.
+    pub show_missing_glyphs: bool,
 }
 
 impl Default for TextMeshStyle {
     fn default() -> Self {
         Self {
             depth: 0.1,
+            font_size: 1.0,
+            line_spacing: 1.0,
             subdivision: 20, // Default low poly-ish but smooth enough
             anchor: TextAnchor::TopLeft,
             justify: JustifyText::Left,
+            direction: TextDirection::Auto,
+            writing_mode: WritingMode::Horizontal,
+            kerning: true,
+            letter_spacing: 0.0,
+            word_spacing: 0.0,
+            max_width: None,
+            wrap: TextWrap::Word,
+            path: None,
+            deform: Vec::new(),
+            extrusion: TextExtrusion::Extruded,
+            fill: TextFill::Solid,
+            bevel: None,
+            uv_mapping: TextUvMapping::Glyph,
+            generate_tangents: false,
+            fallback_fonts: Vec::new(),
+            show_missing_glyphs: false,
         }
     }
 }
@@ -230,6 +770,10 @@ impl Default for TextMeshStyle {
 ///
 /// Each child entity will have a [`GlyphMesh`] component with its character index.
 ///
+/// When the text changes, child entities of unchanged glyphs are kept and moved
+/// into place, so per-glyph materials and components survive edits. Only glyphs
+/// that were inserted or removed are spawned or despawned.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -273,6 +817,10 @@ pub struct TextMeshGlyphs {
 /// - `char_index`: The index of this character in the original text string
 /// - `line_index`: The line number (0-indexed) this character appears on
 /// - `character`: The actual character this glyph represents
+/// - `glyph_id`: The font glyph rendered for this entity
+///
+/// When shaping combines several characters into one glyph (e.g. an "fi" ligature),
+/// `char_index` and `character` refer to the first character of the group.
 #[derive(Component, Reflect, Clone, Debug)]
 #[reflect(Component)]
 pub struct GlyphMesh {
@@ -282,6 +830,125 @@ pub struct GlyphMesh {
     pub line_index: usize,
     /// The character this glyph represents
     pub character: char,
+    /// ID of the glyph in the font after shaping
+    pub glyph_id: u16,
+}
+
+/// A section of rich text with its own font and size.
+///
+/// Used in [`TextMeshSpans`]. Options left as `None` fall back to the block's
+/// [`TextMeshStyle`].
+#[derive(Reflect, Clone, Debug, Default)]
+pub struct TextMeshSpan {
+    /// The text of this span. Use `\n` for line breaks.
+    pub text: String,
+    /// Handle to the font asset used for this span, e.g. a bold or italic face.
+    pub font: Handle<FontMesh>,
+    /// Extrusion depth of this span, overriding [`TextMeshStyle::depth`].
+    pub depth: Option<f32>,
+    /// Font size of this span, overriding [`TextMeshStyle::font_size`].
+    pub font_size: Option<f32>,
+    /// User-defined tag copied to the span's [`SpanMesh`], e.g. to pick a material.
+    pub tag: u32,
+}
+
+impl TextMeshSpan {
+    /// Create a span of text in the given font.
+    pub fn new(text: impl Into<String>, font: Handle<FontMesh>) -> Self {
+        Self {
+            text: text.into(),
+            font,
+            ..default()
+        }
+    }
+
+    /// Set the user-defined tag of this span.
+    pub fn with_tag(mut self, tag: u32) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Resolve the style used to shape and extrude this span.
+    pub(crate) fn resolve_style(&self, style: &TextMeshStyle) -> TextMeshStyle {
+        TextMeshStyle {
+            depth: self.depth.unwrap_or(style.depth),
+            font_size: self.font_size.unwrap_or(style.font_size),
+            ..style.clone()
+        }
+    }
+}
+
+/// Component for rich text made of spans with different fonts and sizes.
+///
+/// All spans are laid out as one flowing block: they share lines, wrapping,
+/// justification and anchoring from [`style`](Self::style). One child entity with a
+/// [`SpanMesh`] component is spawned per span, so each span can use its own
+/// material. New span entities copy the parent's material.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<StandardMaterial>>,
+/// # ) {
+/// let regular = asset_server.load("fonts/Regular.ttf");
+/// let bold = asset_server.load("fonts/Bold.ttf");
+///
+/// commands.spawn(TextMeshSpansBundle {
+///     text_spans: TextMeshSpans {
+///         spans: vec![
+///             TextMeshSpan::new("Press ", regular.clone()),
+///             TextMeshSpan::new("START", bold).with_tag(1),
+///             TextMeshSpan::new(" to play", regular),
+///         ],
+///         style: TextMeshStyle::default(),
+///     },
+///     material: MeshMaterial3d(materials.add(StandardMaterial::default())),
+///     ..default()
+/// });
+/// # }
+/// ```
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct TextMeshSpans {
+    /// The spans of text, in reading order.
+    pub spans: Vec<TextMeshSpan>,
+    /// Style of the whole block. Layout options apply to all spans; geometry
+    /// options apply unless overridden by a span.
+    pub style: TextMeshStyle,
+}
+
+/// Marker component for span mesh entities spawned by [`TextMeshSpans`].
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct SpanMesh {
+    /// Index of the span in [`TextMeshSpans::spans`]
+    pub span_index: usize,
+    /// The span's [`TextMeshSpan::tag`]
+    pub tag: u32,
+}
+
+/// Convenience bundle for spawning rich text with one entity per span.
+#[derive(Bundle, Default)]
+pub struct TextMeshSpansBundle {
+    /// The spans component that drives per-span mesh generation.
+    pub text_spans: TextMeshSpans,
+    /// Default material for span meshes (can be overridden per span).
+    pub material: MeshMaterial3d<StandardMaterial>,
+    /// Local transform of the parent entity.
+    pub transform: Transform,
+    /// Global transform (computed automatically).
+    pub global_transform: GlobalTransform,
+    /// Visibility of the entity.
+    pub visibility: Visibility,
+    /// Inherited visibility (computed automatically).
+    pub inherited_visibility: InheritedVisibility,
+    /// View visibility (computed automatically).
+    pub view_visibility: ViewVisibility,
 }
 
 /// Convenience bundle for spawning 3D text with per-character entities.
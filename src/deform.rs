@@ -0,0 +1,88 @@
+//! Vertex modifiers applied to combined text meshes after layout.
+//!
+//! Each [`TextDeform`] maps positions in the anchored text space. Normals are
+//! transformed by the cofactor of the modifier's Jacobian, estimated with central
+//! differences, so they stay perpendicular to the deformed surface.
+
+use crate::component::TextDeform;
+use crate::outline::GlyphGeometry;
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// Step used to estimate the Jacobian of a modifier
+const EPSILON: f32 = 1e-3;
+
+impl TextDeform {
+    /// Deform a position. `x_range` is the horizontal extent of the whole text.
+    fn apply(&self, position: Vec3, x_range: (f32, f32)) -> Vec3 {
+        let Vec3 { x, y, z } = position;
+        match *self {
+            TextDeform::Bend { radius } if radius != 0.0 => {
+                let angle = x / radius;
+                let distance = radius + z;
+                Vec3::new(distance * angle.sin(), y, distance * angle.cos() - radius)
+            }
+            TextDeform::Twist { angle } => {
+                let (sin, cos) = (angle * x).sin_cos();
+                Vec3::new(x, y * cos - z * sin, y * sin + z * cos)
+            }
+            TextDeform::Taper { start, end } => {
+                let (min, max) = x_range;
+                let t = if max > min {
+                    (x - min) / (max - min)
+                } else {
+                    0.0
+                };
+                Vec3::new(x, y * (start + (end - start) * t), z)
+            }
+            TextDeform::Wave {
+                amplitude,
+                wavelength,
+                phase,
+            } if wavelength != 0.0 => {
+                Vec3::new(x, y + amplitude * (TAU * x / wavelength + phase).sin(), z)
+            }
+            _ => position,
+        }
+    }
+
+    /// Deform a normal at a position, before the position itself is deformed.
+    fn apply_normal(&self, position: Vec3, normal: Vec3, x_range: (f32, f32)) -> Vec3 {
+        let derivative = |axis: Vec3| {
+            (self.apply(position + axis * EPSILON, x_range)
+                - self.apply(position - axis * EPSILON, x_range))
+                / (2.0 * EPSILON)
+        };
+        let (dx, dy, dz) = (
+            derivative(Vec3::X),
+            derivative(Vec3::Y),
+            derivative(Vec3::Z),
+        );
+
+        // The cofactor matrix maps normals without inverting the Jacobian
+        let cofactor = Mat3::from_cols(dy.cross(dz), dz.cross(dx), dx.cross(dy));
+        (cofactor * normal).try_normalize().unwrap_or(normal)
+    }
+}
+
+/// Apply modifiers in order to the vertices and normals of a text's meshes.
+pub(crate) fn deform_geometry(deforms: &[TextDeform], buffers: &mut [GlyphGeometry]) {
+    for deform in deforms {
+        let x_range = buffers
+            .iter()
+            .flat_map(|buffer| &buffer.vertices)
+            .fold((f32::MAX, f32::MIN), |(min, max), v| {
+                (min.min(v[0]), max.max(v[0]))
+            });
+
+        for buffer in buffers.iter_mut() {
+            for (vertex, normal) in buffer.vertices.iter_mut().zip(&mut buffer.normals) {
+                let position = Vec3::from(*vertex);
+                *normal = deform
+                    .apply_normal(position, Vec3::from(*normal), x_range)
+                    .into();
+                *vertex = deform.apply(position, x_range).into();
+            }
+        }
+    }
+}
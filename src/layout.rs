@@ -0,0 +1,791 @@
+//! Shared layout helpers used by the mesh systems and [`FontMesh`](crate::FontMesh) queries.
+//!
+//! Text is shaped line by line with rustybuzz, which applies the font's
+//! substitutions (ligatures, contextual alternates) and positioning (kerning,
+//! mark attachment) and maps every glyph back to the characters it came from.
+//! Lines are broken in logical order and then reordered into visual runs with the
+//! Unicode Bidirectional Algorithm, so mixed left-to-right and right-to-left text
+//! is displayed correctly.
+//!
+//! Lines are laid out along an inline axis (rightwards for horizontal text,
+//! downwards for vertical text) and only mapped to X/Y once they are positioned,
+//! so wrapping and justification work the same in every [`WritingMode`].
+
+use crate::component::{
+    JustifyText, TextAnchor, TextDirection, TextMeshStyle, TextWrap, WritingMode,
+};
+use crate::outline::notdef_advance;
+use crate::path::PathSampler;
+use bevy::math::{Vec2, Vec3};
+use rustybuzz::{Direction, Feature, UnicodeBuffer};
+use std::ops::Range;
+use ttf_parser::{GlyphId, Tag};
+use unicode_bidi::{BidiInfo, Level};
+use unicode_linebreak::linebreaks;
+
+/// A single shaped glyph within a line.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ShapedGlyph {
+    /// Glyph to render
+    pub glyph_id: GlyphId,
+    /// Index (in chars) of the first character of this glyph's cluster within the line
+    pub char_index: usize,
+    /// The first character of this glyph's cluster
+    pub character: char,
+    /// Position of the glyph origin relative to the line start, along the inline
+    /// axis (`x`) and the cross axis (`y`)
+    pub offset: Vec2,
+}
+
+/// A shaped line of text.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ShapedLine {
+    /// Glyphs in visual order (left to right, or top to bottom)
+    pub glyphs: Vec<ShapedGlyph>,
+    /// Total advance of the line along the inline axis, without `trailing_spacing`
+    pub width: f32,
+    /// Letter spacing after the line's last character, which is not part of its width
+    pub trailing_spacing: f32,
+    /// Advance of each character's cluster, indexed by char (zero for characters
+    /// that continue a cluster)
+    pub advances: Vec<f32>,
+    /// Char indices in visual order (left to right, or top to bottom)
+    pub visual_chars: Vec<usize>,
+    /// Visible characters the font has no glyph for, in visual order
+    pub missing: Vec<char>,
+}
+
+/// A laid out text block.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TextLayout {
+    /// Positioned glyphs of all lines
+    pub glyphs: Vec<LayoutGlyph>,
+    /// Visible characters that no font had a glyph for, without duplicates
+    pub missing: Vec<char>,
+}
+
+/// A glyph positioned within a whole (possibly multiline) text block.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LayoutGlyph {
+    /// Glyph to render
+    pub glyph_id: GlyphId,
+    /// Index (in chars) of the glyph's first character in the full text
+    pub char_index: usize,
+    /// Line number this glyph is on (0-indexed)
+    pub line_index: usize,
+    /// Index of the [`TextRun`] this glyph belongs to
+    pub run_index: usize,
+    /// Index of the glyph's font within its run's [`faces`](TextRun::faces)
+    pub face_index: usize,
+    /// The first character of this glyph's cluster
+    pub character: char,
+    /// Position of the glyph origin
+    pub position: Vec2,
+}
+
+/// Get the fallback advance used for whitespace the font has no glyph for
+#[inline]
+fn whitespace_fallback_advance(face: &fontmesh::Face) -> f32 {
+    // Use font metrics for a proportional fallback space width
+    // Typically ~25% of the font height is a good space width
+    (fontmesh::ascender(face) - fontmesh::descender(face)) * 0.25
+}
+
+/// Calculate the X offset based on text justification
+///
+/// `rtl` is the base direction of the line's paragraph, which decides where
+/// [`JustifyText::Start`] and [`JustifyText::End`] lines go.
+#[inline]
+fn calculate_justification_offset(justify: JustifyText, line_width: f32, rtl: bool) -> f32 {
+    match justify {
+        JustifyText::Left => 0.0,
+        JustifyText::Center => -line_width * 0.5,
+        JustifyText::Right => -line_width,
+        JustifyText::Start | JustifyText::Justified if !rtl => 0.0,
+        JustifyText::End if rtl => 0.0,
+        JustifyText::Start | JustifyText::End | JustifyText::Justified => -line_width,
+    }
+}
+
+/// Helper function to calculate anchor offset for text positioning
+fn calculate_anchor_offset(anchor: TextAnchor, min_bound: Vec3, max_bound: Vec3) -> Vec3 {
+    let size = max_bound - min_bound;
+    let pivot_pos = min_bound.truncate() + size.truncate() * anchor.pivot();
+    Vec3::new(-pivot_pos.x, -pivot_pos.y, 0.0)
+}
+
+/// Anchor offset of a text block, placing it along the style's path if it has one.
+pub(crate) fn layout_offset(
+    style: &TextMeshStyle,
+    path: Option<&PathSampler>,
+    min_bound: Vec3,
+    max_bound: Vec3,
+) -> Vec3 {
+    let mut offset = calculate_anchor_offset(style.anchor, min_bound, max_bound);
+    if let Some(path) = path {
+        offset.x += path.length() * style.anchor.pivot().x;
+    }
+    offset
+}
+
+/// Whether a paragraph is laid out right-to-left.
+fn is_rtl_paragraph(text: &str, direction: TextDirection) -> bool {
+    match direction {
+        TextDirection::Auto => {
+            unicode_bidi::get_base_direction(text) == unicode_bidi::Direction::Rtl
+        }
+        TextDirection::LeftToRight => false,
+        TextDirection::RightToLeft => true,
+    }
+}
+
+/// Stretch a line to `target_width` by widening its inter-word gaps.
+///
+/// Only whitespace visually between two visible words counts as a gap; lines
+/// without gaps are left unchanged.
+fn justify_line(line: &mut ShapedLine, text: &str, target_width: f32) {
+    let chars: Vec<char> = text.chars().collect();
+    let order = &line.visual_chars;
+    let is_space = |position: usize| chars[order[position]].is_whitespace();
+    let Some(first) = (0..order.len()).find(|&v| !is_space(v)) else {
+        return;
+    };
+    let last = (0..order.len()).rfind(|&v| !is_space(v)).unwrap_or(first);
+
+    let gaps: Vec<usize> = (first..last)
+        .filter(|&v| is_space(v) && !is_space(v - 1))
+        .collect();
+    let extra = target_width - line.width;
+    if gaps.is_empty() || extra <= 0.0 {
+        return;
+    }
+
+    let mut visual_positions = vec![0; chars.len()];
+    for (position, &char_index) in order.iter().enumerate() {
+        visual_positions[char_index] = position;
+    }
+
+    let per_gap = extra / gaps.len() as f32;
+    for glyph in &mut line.glyphs {
+        // Count the gaps visually to the left of this glyph
+        let position = visual_positions[glyph.char_index];
+        let gaps_before = gaps.iter().filter(|&&gap| gap < position).count();
+        glyph.offset.x += per_gap * gaps_before as f32;
+    }
+    line.width = target_width;
+}
+
+/// Shape a single line of text, in world units.
+///
+/// The paragraph direction follows [`TextMeshStyle::direction`] and mixed-direction
+/// text is reordered for display.
+pub(crate) fn shape_line(face: &rustybuzz::Face, line: &str, style: &TextMeshStyle) -> ShapedLine {
+    let runs = [TextRun {
+        faces: &[face],
+        text: line,
+        style,
+    }];
+    let piece = Piece {
+        run_index: 0,
+        face_index: 0,
+        text: line,
+        char_start: 0,
+    };
+    let rtl = !style.writing_mode.is_vertical() && is_rtl_paragraph(line, style.direction);
+    shape_pieces(&runs, &[piece], style, rtl).shaped
+}
+
+/// Shape text in a single direction, in world units.
+///
+/// Vertical text is shaped top to bottom with each glyph centered on the cross axis.
+/// Letter and word spacing are added after each character, except that the letter
+/// spacing after the last one is left out of the width.
+/// Missing glyphs produce no output unless [`TextMeshStyle::show_missing_glyphs`]
+/// is set, in which case they are kept as `.notdef` glyphs with a proper advance.
+/// Missing whitespace still advances by a proportional fallback width.
+fn shape_run(
+    face: &rustybuzz::Face,
+    text: &str,
+    style: &TextMeshStyle,
+    direction: Direction,
+) -> ShapedLine {
+    if text.is_empty() {
+        return ShapedLine::default();
+    }
+
+    let scale = style.font_size / face.units_per_em() as f32;
+    let fallback_advance = whitespace_fallback_advance(face) * style.font_size;
+
+    let mut features = Vec::new();
+    if !style.kerning {
+        features.push(Feature::new(Tag::from_bytes(b"kern"), 0, ..));
+    }
+
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(text);
+    buffer.set_direction(direction);
+    buffer.guess_segment_properties();
+    let output = rustybuzz::shape(face, &features, buffer);
+
+    // Map cluster byte offsets back to char indices
+    let char_indices: Vec<(usize, char)> = text.char_indices().collect();
+    let char_at = |byte: usize| {
+        let index = char_indices.partition_point(|(b, _)| *b < byte);
+        (index, char_indices.get(index).map_or(' ', |(_, ch)| *ch))
+    };
+
+    let mut glyphs = Vec::with_capacity(output.len());
+    let mut advances = vec![0.0; char_indices.len()];
+    let mut missing = Vec::new();
+    let mut pen = 0.0;
+    let mut trailing_spacing = 0.0;
+    let infos = output.glyph_infos();
+
+    for (i, (info, pos)) in infos.iter().zip(output.glyph_positions()).enumerate() {
+        let (char_index, character) = char_at(info.cluster as usize);
+        let glyph_id = GlyphId(info.glyph_id as u16);
+
+        // Map to the inline and cross axes (vertical positions run downwards)
+        let (offset, mut advance) = if direction == Direction::TopToBottom {
+            (
+                Vec2::new(-pos.y_offset as f32, pos.x_offset as f32) * scale,
+                -pos.y_advance as f32 * scale,
+            )
+        } else {
+            (
+                Vec2::new(pos.x_offset as f32, pos.y_offset as f32) * scale,
+                pos.x_advance as f32 * scale,
+            )
+        };
+
+        let mut visible = true;
+        if glyph_id.0 == 0 {
+            // Glyph is missing from the font
+            if character.is_whitespace() {
+                advance = fallback_advance;
+                visible = false;
+            } else {
+                missing.push(character);
+                if !style.show_missing_glyphs {
+                    continue;
+                }
+
+                // Fonts don't always give `.notdef` an advance, so use the box width
+                advance = notdef_advance(face) * style.font_size;
+            }
+        }
+
+        if visible {
+            glyphs.push(ShapedGlyph {
+                glyph_id,
+                char_index,
+                character,
+                offset: Vec2::new(pen, 0.0) + offset,
+            });
+        }
+
+        // Spacing goes after the last glyph of a cluster so marks stay attached
+        trailing_spacing = 0.0;
+        if infos
+            .get(i + 1)
+            .is_none_or(|next| next.cluster != info.cluster)
+        {
+            advance += style.letter_spacing;
+            trailing_spacing = style.letter_spacing;
+            if character.is_whitespace() {
+                advance += style.word_spacing;
+            }
+        }
+        pen += advance;
+        advances[char_index] += advance;
+    }
+
+    let visual_chars = if direction == Direction::RightToLeft {
+        (0..char_indices.len()).rev().collect()
+    } else {
+        (0..char_indices.len()).collect()
+    };
+
+    ShapedLine {
+        glyphs,
+        width: pen - trailing_spacing,
+        trailing_spacing,
+        advances,
+        visual_chars,
+        missing,
+    }
+}
+
+/// Get the line height of a font
+#[inline]
+pub(crate) fn line_height(face: &fontmesh::Face) -> f32 {
+    fontmesh::ascender(face) - fontmesh::descender(face) + fontmesh::line_gap(face)
+}
+
+/// Get the column width of a font for vertical text, from `vhea` if present
+fn column_width(face: &fontmesh::Face) -> f32 {
+    match (face.vertical_ascender(), face.vertical_descender()) {
+        (Some(ascender), Some(descender)) => {
+            let line_gap = face.vertical_line_gap().unwrap_or(0);
+            (ascender as f32 - descender as f32 + line_gap as f32) / face.units_per_em() as f32
+        }
+        _ => line_height(face),
+    }
+}
+
+/// Split a paragraph into wrapped lines according to the style's wrap settings.
+///
+/// Returns the char range of each line within the paragraph. Trailing whitespace
+/// at a wrap point and the letter spacing after a line's last character do not
+/// count towards the line width. In [`TextWrap::Word`]
+/// mode a single word wider than `max_width` overflows on its own line.
+fn wrap_paragraph(
+    paragraph: &str,
+    shaped: &ShapedLine,
+    style: &TextMeshStyle,
+) -> Vec<Range<usize>> {
+    let char_count = shaped.advances.len();
+    let max_width = match style.max_width {
+        Some(max_width) if style.wrap != TextWrap::NoWrap => max_width,
+        _ => f32::INFINITY,
+    };
+
+    let chars: Vec<char> = paragraph.chars().collect();
+    let width_of = |range: Range<usize>| {
+        let mut end = range.end;
+        while end > range.start && chars[end - 1].is_whitespace() {
+            end -= 1;
+        }
+        if end == range.start {
+            return 0.0;
+        }
+        shaped.advances[range.start..end].iter().sum::<f32>() - style.letter_spacing
+    };
+
+    // Char positions where a new line is allowed to start
+    let breaks: Vec<usize> = if shaped.width <= max_width {
+        Vec::new()
+    } else if style.wrap == TextWrap::Character {
+        (1..=char_count).collect()
+    } else {
+        let byte_to_char: Vec<usize> = paragraph.char_indices().map(|(b, _)| b).collect();
+        linebreaks(paragraph)
+            .map(|(byte, _)| byte_to_char.partition_point(|b| *b < byte))
+            .collect()
+    };
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut candidate = None;
+
+    for end in breaks {
+        if end <= start {
+            continue;
+        }
+        if width_of(start..end) > max_width {
+            if let Some(previous) = candidate.filter(|c| *c > start) {
+                lines.push(start..previous);
+                start = previous;
+            }
+        }
+        candidate = Some(end);
+    }
+
+    lines.push(start..char_count);
+    lines
+}
+
+/// A run of text sharing one font and style, e.g. a rich text span.
+#[derive(Clone, Copy)]
+pub(crate) struct TextRun<'a> {
+    /// The run's font followed by its fallbacks, in order of preference
+    pub faces: &'a [&'a rustybuzz::Face<'a>],
+    pub text: &'a str,
+    /// Shaping and geometry style of the run (font size, kerning, depth)
+    pub style: &'a TextMeshStyle,
+}
+
+/// The part of a run that falls within one paragraph and uses a single font.
+#[derive(Clone, Copy)]
+struct Piece<'a> {
+    run_index: usize,
+    face_index: usize,
+    text: &'a str,
+    /// Char index of the piece's first character within its paragraph
+    char_start: usize,
+}
+
+/// A wrapped line waiting to be positioned.
+struct PendingLine {
+    shaped: ShapedLine,
+    /// Run and face index of each glyph in `shaped.glyphs`
+    glyph_runs: Vec<(usize, usize)>,
+    text: String,
+    /// Char index of the line's first character in the full text
+    char_start: usize,
+    /// Distance from the previous baseline (or column) to this one
+    height: f32,
+    /// Whether the line's paragraph is right-to-left
+    rtl: bool,
+    /// Whether this line ends its paragraph
+    last_in_paragraph: bool,
+}
+
+/// Split a horizontal line into parts of its pieces, in visual order.
+///
+/// The line is split into directional runs with the Unicode Bidirectional
+/// Algorithm using the paragraph's base direction. Returns the piece index, byte
+/// range and shaping direction of each part.
+fn visual_segments(
+    text: &str,
+    piece_ranges: &[Range<usize>],
+    rtl: bool,
+) -> Vec<(usize, Range<usize>, Direction)> {
+    let base_level = if rtl { Level::rtl() } else { Level::ltr() };
+    let bidi = BidiInfo::new(text, Some(base_level));
+    let mut segments = Vec::new();
+
+    for paragraph in &bidi.paragraphs {
+        let (levels, level_runs) = bidi.visual_runs(paragraph, paragraph.range.clone());
+
+        for level_run in level_runs {
+            let direction = if levels[level_run.start].is_rtl() {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
+            };
+
+            // Parts of pieces within this run, in visual order
+            let start = segments.len();
+            segments.extend(piece_ranges.iter().enumerate().filter_map(|(i, range)| {
+                let start = range.start.max(level_run.start);
+                let end = range.end.min(level_run.end);
+                (start < end).then_some((i, start..end, direction))
+            }));
+            if direction == Direction::RightToLeft {
+                segments[start..].reverse();
+            }
+        }
+    }
+
+    segments
+}
+
+/// Shape the pieces of a line into a single line in visual order.
+///
+/// Horizontal lines are reordered with [`visual_segments`] and each part is shaped
+/// in its own direction; vertical lines are shaped top to bottom in logical order.
+fn shape_pieces(
+    runs: &[TextRun],
+    pieces: &[Piece],
+    block_style: &TextMeshStyle,
+    rtl: bool,
+) -> PendingLine {
+    let mut line = PendingLine {
+        shaped: ShapedLine::default(),
+        glyph_runs: Vec::new(),
+        text: String::new(),
+        char_start: 0,
+        height: 0.0,
+        rtl,
+        last_in_paragraph: true,
+    };
+    let vertical = block_style.writing_mode.is_vertical();
+
+    // Byte range of each piece within the line
+    let mut piece_ranges = Vec::with_capacity(pieces.len());
+    for piece in pieces {
+        let start = line.text.len();
+        line.text.push_str(piece.text);
+        piece_ranges.push(start..line.text.len());
+
+        let run = &runs[piece.run_index];
+        let face = &run.faces[piece.face_index];
+        let height = if vertical {
+            column_width(face)
+        } else {
+            line_height(face)
+        };
+        line.height = line
+            .height
+            .max(height * run.style.font_size * block_style.line_spacing);
+    }
+
+    let char_count = line.text.chars().count();
+    line.shaped.advances = vec![0.0; char_count];
+    let text = line.text.as_str();
+    let segments = if vertical {
+        piece_ranges
+            .into_iter()
+            .enumerate()
+            .map(|(i, range)| (i, range, Direction::TopToBottom))
+            .collect()
+    } else {
+        visual_segments(text, &piece_ranges, rtl)
+    };
+
+    for (piece_index, range, direction) in segments {
+        let piece = &pieces[piece_index];
+        let run = &runs[piece.run_index];
+        let face = &run.faces[piece.face_index];
+        let shaped = shape_run(face, &text[range.clone()], run.style, direction);
+        // Spacing between segments still separates their characters
+        let pen = line.shaped.width + line.shaped.trailing_spacing;
+        let char_offset = text[..range.start].chars().count();
+
+        line.shaped
+            .glyphs
+            .extend(shaped.glyphs.iter().map(|glyph| ShapedGlyph {
+                char_index: glyph.char_index + char_offset,
+                offset: glyph.offset + Vec2::new(pen, 0.0),
+                ..*glyph
+            }));
+        line.glyph_runs.extend(std::iter::repeat_n(
+            (piece.run_index, piece.face_index),
+            shaped.glyphs.len(),
+        ));
+        for (i, advance) in shaped.advances.iter().enumerate() {
+            line.shaped.advances[char_offset + i] += advance;
+        }
+        line.shaped
+            .visual_chars
+            .extend(shaped.visual_chars.iter().map(|i| i + char_offset));
+        line.shaped.width = pen + shaped.width;
+        line.shaped.trailing_spacing = shaped.trailing_spacing;
+        line.shaped.missing.extend(shaped.missing);
+    }
+
+    line
+}
+
+/// Split text into pieces by the first face in `faces` that has a glyph for each character.
+///
+/// Whitespace stays with the current face when it covers it, so spaces between
+/// fallback characters don't break up shaping. Characters no face covers use the
+/// primary face.
+fn split_by_coverage<'a>(faces: &[&rustybuzz::Face], piece: Piece<'a>) -> Vec<Piece<'a>> {
+    if faces.len() < 2 {
+        return vec![piece];
+    }
+
+    let covers = |face_index: usize, ch: char| faces[face_index].glyph_index(ch).is_some();
+    let mut pieces = Vec::new();
+    let (mut start_byte, mut start_char) = (0, piece.char_start);
+    let mut current = None;
+
+    for (char_offset, (byte, ch)) in piece.text.char_indices().enumerate() {
+        let face_index = match current {
+            Some(current) if ch.is_whitespace() && covers(current, ch) => current,
+            _ => (0..faces.len()).find(|&i| covers(i, ch)).unwrap_or(0),
+        };
+        if let Some(current) = current.filter(|&current| current != face_index) {
+            pieces.push(Piece {
+                face_index: current,
+                text: &piece.text[start_byte..byte],
+                char_start: start_char,
+                ..piece
+            });
+            (start_byte, start_char) = (byte, piece.char_start + char_offset);
+        }
+        current = Some(face_index);
+    }
+
+    pieces.push(Piece {
+        face_index: current.unwrap_or(0),
+        text: &piece.text[start_byte..],
+        char_start: start_char,
+        ..piece
+    });
+    pieces
+}
+
+/// Restrict paragraph pieces to the char range of a wrapped line.
+fn clip_pieces<'a>(pieces: &[Piece<'a>], range: Range<usize>) -> Vec<Piece<'a>> {
+    pieces
+        .iter()
+        .filter_map(|piece| {
+            let piece_end = piece.char_start + piece.text.chars().count();
+            let start = range.start.max(piece.char_start);
+            let end = range.end.min(piece_end);
+            // Keep empty pieces on empty lines so the line still gets a height
+            if start > end || (start == end && !range.is_empty()) {
+                return None;
+            }
+
+            let byte = |char_index: usize| {
+                piece
+                    .text
+                    .char_indices()
+                    .nth(char_index - piece.char_start)
+                    .map_or(piece.text.len(), |(b, _)| b)
+            };
+            Some(Piece {
+                text: &piece.text[byte(start)..byte(end)],
+                char_start: start,
+                ..*piece
+            })
+        })
+        .collect()
+}
+
+/// Lay out consecutive runs of text as one flowing text block, splitting on `\n`,
+/// wrapping and applying justification.
+///
+/// Lines are stacked downwards from a baseline at `y = 0`, or vertical columns
+/// sideways from `x = 0` with characters stacking down from `y = 0`. Runs are shaped
+/// separately but wrapped and justified together according to the block style.
+/// Characters missing from a run's font are shaped with its first fallback face
+/// that has them. Each line is as tall as its tallest run.
+pub(crate) fn layout_runs(runs: &[TextRun], style: &TextMeshStyle) -> TextLayout {
+    // Split runs into paragraphs of pieces
+    let mut paragraphs: Vec<Vec<Piece>> = vec![Vec::new()];
+    for (run_index, run) in runs.iter().enumerate() {
+        for (i, text) in run.text.split('\n').enumerate() {
+            if i > 0 {
+                paragraphs.push(Vec::new());
+            }
+            let paragraph = paragraphs.last_mut().unwrap();
+            let char_start = paragraph
+                .iter()
+                .map(|piece| piece.text.chars().count())
+                .sum();
+            let piece = Piece {
+                run_index,
+                face_index: 0,
+                text,
+                char_start,
+            };
+            paragraph.extend(split_by_coverage(run.faces, piece));
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut paragraph_char_start = 0;
+
+    for pieces in &paragraphs {
+        let text: String = pieces.iter().map(|piece| piece.text).collect();
+        let rtl = !style.writing_mode.is_vertical() && is_rtl_paragraph(&text, style.direction);
+        let mut whole = shape_pieces(runs, pieces, style, rtl);
+        let ranges = wrap_paragraph(&whole.text, &whole.shaped, style);
+        let char_count = whole.shaped.advances.len();
+
+        if ranges.len() == 1 {
+            whole.char_start = paragraph_char_start;
+            lines.push(whole);
+        } else {
+            let chars: Vec<char> = whole.text.chars().collect();
+            for (range_index, range) in ranges.iter().enumerate() {
+                // Re-shape wrapped lines so shaping and justification see only their own text
+                let last_in_paragraph = range_index + 1 == ranges.len();
+                let mut end = range.end;
+                if !last_in_paragraph {
+                    while end > range.start && chars[end - 1].is_whitespace() {
+                        end -= 1;
+                    }
+                }
+
+                let mut line =
+                    shape_pieces(runs, &clip_pieces(pieces, range.start..end), style, rtl);
+                line.char_start = paragraph_char_start + range.start;
+                line.last_in_paragraph = last_in_paragraph;
+                lines.push(line);
+            }
+        }
+
+        // Account for the newline character in char indices
+        paragraph_char_start += char_count + 1;
+    }
+
+    // Wrapped lines fill the wrap width; only they can end before their paragraph
+    if let (JustifyText::Justified, Some(max_width)) = (style.justify, style.max_width) {
+        for line in lines.iter_mut().filter(|line| !line.last_in_paragraph) {
+            justify_line(&mut line.shaped, &line.text, max_width);
+        }
+    }
+
+    let mut layout = TextLayout::default();
+    let mut block_offset = 0.0;
+
+    for (line_index, line) in lines.iter().enumerate() {
+        if line_index > 0 {
+            block_offset += line.height;
+        }
+        let inline_offset =
+            calculate_justification_offset(style.justify, line.shaped.width, line.rtl);
+
+        // Map inline and cross axis offsets to X/Y
+        let position = |offset: Vec2| match style.writing_mode {
+            WritingMode::Horizontal => Vec2::new(inline_offset + offset.x, offset.y - block_offset),
+            WritingMode::VerticalRightToLeft => {
+                Vec2::new(offset.y - block_offset, -(inline_offset + offset.x))
+            }
+            WritingMode::VerticalLeftToRight => {
+                Vec2::new(offset.y + block_offset, -(inline_offset + offset.x))
+            }
+        };
+
+        for &character in &line.shaped.missing {
+            if !layout.missing.contains(&character) {
+                layout.missing.push(character);
+            }
+        }
+        layout
+            .glyphs
+            .extend(line.shaped.glyphs.iter().zip(&line.glyph_runs).map(
+                |(glyph, &(run_index, face_index))| LayoutGlyph {
+                    glyph_id: glyph.glyph_id,
+                    char_index: line.char_start + glyph.char_index,
+                    line_index,
+                    run_index,
+                    face_index,
+                    character: glyph.character,
+                    position: position(glyph.offset),
+                },
+            ));
+    }
+
+    layout
+}
+
+/// Calculate the x position of every character in a single line.
+///
+/// Characters that share a cluster (e.g. the letters of a ligature) split the
+/// cluster's advance evenly, which gives sensible caret positions.
+pub(crate) fn char_offsets(face: &rustybuzz::Face, line: &str, style: &TextMeshStyle) -> Vec<f32> {
+    let shaped = shape_line(face, line, style);
+    let char_count = line.chars().count();
+
+    // Cluster start positions, sorted by character index
+    let mut clusters: Vec<(usize, f32)> = shaped
+        .glyphs
+        .iter()
+        .map(|g| (g.char_index, g.offset.x))
+        .collect();
+    clusters.sort_by_key(|(index, _)| *index);
+    clusters.dedup_by_key(|(index, _)| *index);
+
+    let mut offsets = vec![0.0; char_count];
+    let mut pen = 0.0;
+    let mut next_cluster = 0;
+
+    for (index, offset) in offsets.iter_mut().enumerate() {
+        // Find the cluster containing this character
+        while next_cluster < clusters.len() && clusters[next_cluster].0 <= index {
+            next_cluster += 1;
+        }
+        let Some(&(start, start_x)) = next_cluster.checked_sub(1).map(|i| &clusters[i]) else {
+            *offset = pen;
+            continue;
+        };
+        let (end, end_x) = clusters
+            .get(next_cluster)
+            .copied()
+            .unwrap_or((char_count, shaped.width));
+
+        let fraction = (index - start) as f32 / (end - start) as f32;
+        *offset = start_x + (end_x - start_x) * fraction;
+        pen = *offset;
+    }
+
+    offsets
+}
@@ -39,32 +39,69 @@
 //!
 //! - Generates 3D mesh geometry from TrueType fonts
 //! - Supports multiline text with `\n` line breaks
+//! - Rich text spans mixing fonts and sizes in one flowing paragraph
+//! - Fallback fonts for characters missing from the primary font
+//! - Optional `.notdef` boxes and [`MissingGlyphs`] messages for unsupported characters
+//! - Automatic word or character wrapping at a maximum width
 //! - Configurable text anchoring (9 presets + custom pivot points)
-//! - Text justification (left, center, right)
+//! - Text justification (left, center, right, justified, start, end)
+//! - Bidirectional text: right-to-left scripts and mixed-direction lines
+//! - Vertical text in top-to-bottom columns using the font's vertical metrics
+//! - Text along arcs, Bezier or Catmull-Rom splines and polylines
+//! - Bend, twist, taper and wave modifiers for combined meshes
+//! - OpenType shaping (ligatures, contextual alternates, marks, kerning)
+//! - Font size in world units, independent of extrusion depth and line spacing
+//! - Letter spacing (tracking) and word spacing
 //! - Adjustable extrusion depth and curve subdivision
+//! - Flat front-face-only meshes, optionally double-sided, for decals and `Mesh2d`
+//! - Chamfered or rounded bevels on extruded edges
+//! - Hollow outline (stroke) text for neon and HUD styles
+//! - UV coordinates and optional tangents for textured and normal-mapped materials
 //! - Automatic mesh regeneration when text or style changes
+//! - Glyph geometry cache shared by all text entities
+//! - Flattened glyph and text contours for line renderers, particles or collision shapes
+//! - Background mesh generation that keeps the previous mesh until the new one is ready
+//! - Optional per-frame generation budget for bulk text changes
+//! - Per-glyph entities with any `Material` through [`FontMeshGlyphsPlugin`]
 //!
 //! # Font Format Support
 //!
 //! - TrueType (`.ttf`) fonts are fully supported
-//! - OpenType (`.otf`) fonts with TrueType outlines work
-//! - OpenType fonts with CFF/PostScript outlines are not supported (ttf-parser limitation)
+//! - OpenType (`.otf`) fonts with TrueType or CFF/PostScript outlines are supported
 
 mod asset;
+mod cache;
 mod component;
+mod deform;
+mod layout;
+mod outline;
+mod path;
 pub mod prelude;
+mod settings;
 mod system;
 
-pub use asset::{FontMesh, FontMetrics, GlyphMetrics};
+pub use asset::{FontMesh, FontMetrics, GlyphContour, GlyphMetrics};
+pub use cache::GlyphMeshCache;
 pub use component::{
-    GlyphMesh, JustifyText, TextAnchor, TextMesh, TextMeshBundle, TextMeshGlyphs,
-    TextMeshGlyphsBundle, TextMeshStyle,
+    GlyphMesh, JustifyText, SpanMesh, TextAnchor, TextBevel, TextDeform, TextDirection,
+    TextExtrusion, TextFill, TextMesh, TextMeshBundle, TextMeshGlyphs, TextMeshGlyphsBundle,
+    TextMeshPath, TextMeshSpan, TextMeshSpans, TextMeshSpansBundle, TextMeshStyle, TextUvMapping,
+    TextWrap, WritingMode,
+};
+pub use settings::FontMeshSettings;
+pub use system::{
+    generate_glyph_id_mesh, generate_glyph_mesh, generate_glyph_mesh_with_style, MissingGlyphs,
+    TextMeshComputed, TextMeshGlyphsComputed, TextMeshSpansComputed,
 };
-pub use system::{generate_glyph_mesh, TextMeshComputed, TextMeshGlyphsComputed};
 
 use asset::FontMeshLoader;
 use bevy::prelude::*;
-use system::{update_glyph_meshes, update_text_meshes};
+use cache::evict_glyph_mesh_cache;
+use std::marker::PhantomData;
+use system::{
+    poll_glyph_mesh_tasks, poll_span_mesh_tasks, poll_text_mesh_tasks, reset_frame_budget,
+    update_glyph_meshes, update_span_meshes, update_text_meshes, FrameBudget,
+};
 
 /// Plugin that enables 3D text mesh generation from fonts.
 ///
@@ -87,9 +124,23 @@ use system::{update_glyph_meshes, update_text_meshes};
 /// The plugin automatically:
 /// - Registers the [`FontMesh`] asset type for loading TTF/OTF fonts
 /// - Adds a system that generates meshes when [`TextMesh`] components are added or changed
+/// - Shares tessellated glyphs between all text entities through the [`GlyphMeshCache`]
+/// - Generates meshes in background tasks, configurable through [`FontMeshSettings`]
+/// - Adds [`FontMeshGlyphsPlugin`] for [`StandardMaterial`] glyphs
 /// - Enables reflection for [`TextMesh`] components for editor integration
 pub struct FontMeshPlugin;
 
+/// Ordering of the text mesh systems within `Update`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+enum FontMeshSystems {
+    /// Cache eviction and budget reset
+    Prepare,
+    /// Starting mesh generation for changed text
+    Generate,
+    /// Applying finished background generation
+    Apply,
+}
+
 impl Plugin for FontMeshPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<FontMesh>()
@@ -97,6 +148,107 @@ impl Plugin for FontMeshPlugin {
             .register_type::<TextMesh>()
             .register_type::<TextMeshGlyphs>()
             .register_type::<GlyphMesh>()
-            .add_systems(Update, (update_text_meshes, update_glyph_meshes));
+            .register_type::<TextMeshSpans>()
+            .register_type::<SpanMesh>()
+            .register_type::<FontMeshSettings>()
+            .init_resource::<GlyphMeshCache>()
+            .init_resource::<FontMeshSettings>()
+            .init_resource::<FrameBudget>()
+            .add_message::<MissingGlyphs>()
+            .configure_sets(
+                Update,
+                (
+                    FontMeshSystems::Prepare,
+                    FontMeshSystems::Generate,
+                    FontMeshSystems::Apply,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    (evict_glyph_mesh_cache, reset_frame_budget).in_set(FontMeshSystems::Prepare),
+                    update_text_meshes.in_set(FontMeshSystems::Generate),
+                    poll_text_mesh_tasks.in_set(FontMeshSystems::Apply),
+                ),
+            )
+            .add_plugins(FontMeshGlyphsPlugin::<StandardMaterial>::default());
+    }
+}
+
+/// Plugin that generates child entities for [`TextMeshGlyphs`] and [`TextMeshSpans`]
+/// using material `M`.
+///
+/// [`FontMeshPlugin`] already adds this plugin for [`StandardMaterial`]. Add it for
+/// each custom material (e.g. an `ExtendedMaterial` or toon shader) used on
+/// [`TextMeshGlyphs`] or [`TextMeshSpans`] entities. Newly spawned glyphs and spans
+/// copy the parent's `MeshMaterial3d<M>`.
+///
+/// # Panics
+///
+/// Panics if [`FontMeshPlugin`] has not been added before this plugin.
+///
+/// # Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_fontmesh::prelude::*;
+/// # use bevy::render::render_resource::AsBindGroup;
+/// # #[derive(Asset, TypePath, AsBindGroup, Clone)]
+/// # struct ToonMaterial {}
+/// # impl Material for ToonMaterial {}
+///
+/// fn setup(
+///     mut commands: Commands,
+///     asset_server: Res<AssetServer>,
+///     mut materials: ResMut<Assets<ToonMaterial>>,
+/// ) {
+///     commands.spawn((
+///         TextMeshGlyphs {
+///             text: "Toon".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         MeshMaterial3d(materials.add(ToonMaterial {})),
+///         Transform::default(),
+///         Visibility::default(),
+///     ));
+/// }
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins((
+///         FontMeshPlugin,
+///         MaterialPlugin::<ToonMaterial>::default(),
+///         FontMeshGlyphsPlugin::<ToonMaterial>::default(),
+///     ))
+///     .add_systems(Startup, setup)
+///     .run();
+/// ```
+pub struct FontMeshGlyphsPlugin<M: Material>(PhantomData<M>);
+
+impl<M: Material> Default for FontMeshGlyphsPlugin<M> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<M: Material> Plugin for FontMeshGlyphsPlugin<M> {
+    fn build(&self, app: &mut App) {
+        // The systems rely on the resources and system sets of the main plugin
+        assert!(
+            app.world().contains_resource::<FrameBudget>(),
+            "FontMeshGlyphsPlugin<{}> requires FontMeshPlugin, which must be added first",
+            std::any::type_name::<M>()
+        );
+        app.add_systems(
+            Update,
+            (
+                (update_glyph_meshes::<M>, update_span_meshes::<M>)
+                    .in_set(FontMeshSystems::Generate),
+                (poll_glyph_mesh_tasks::<M>, poll_span_mesh_tasks::<M>)
+                    .in_set(FontMeshSystems::Apply),
+            ),
+        );
     }
 }
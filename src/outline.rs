@@ -0,0 +1,565 @@
+//! Glyph outline extraction and tessellation.
+//!
+//! Meshes are generated from glyph IDs rather than characters so that shaped
+//! output (ligatures, contextual forms, marks) can be rendered directly.
+
+use crate::component::{TextBevel, TextExtrusion, TextFill, TextMeshStyle};
+use bevy::math::{Vec2, Vec3};
+use lyon_tessellation::math::point;
+use lyon_tessellation::path::Path;
+use lyon_tessellation::{BuffersBuilder, FillOptions, FillTessellator, FillVertex, VertexBuffers};
+use ttf_parser::{GlyphId, OutlineBuilder};
+
+/// Side wall normals within this angle of each other are smoothed together.
+const SMOOTHING_ANGLE_COS: f32 = 0.866; // cos(30°)
+
+/// Raw mesh buffers for a single glyph, in world units.
+///
+/// UVs are generated with the [`TextUvMapping::Unwrap`](crate::TextUvMapping::Unwrap) layout and can be
+/// replaced with a planar projection via [`GlyphGeometry::project_uvs`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct GlyphGeometry {
+    pub vertices: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub indices: Vec<u32>,
+}
+
+impl GlyphGeometry {
+    /// Bounds of the vertices on the XY plane.
+    pub fn bounds(&self) -> (Vec2, Vec2) {
+        self.vertices.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), v| {
+                let v = Vec2::new(v[0], v[1]);
+                (min.min(v), max.max(v))
+            },
+        )
+    }
+
+    /// Replace the UVs with a planar front projection onto the rectangle `min..max`.
+    ///
+    /// `offset` is added to every vertex before projecting, which allows projecting
+    /// glyph-local geometry onto text-wide bounds.
+    pub fn project_uvs(&mut self, min: Vec2, max: Vec2, offset: Vec2) {
+        let size = (max - min).max(Vec2::splat(f32::EPSILON));
+        self.uvs = self
+            .vertices
+            .iter()
+            .map(|v| {
+                let uv = (Vec2::new(v[0], v[1]) + offset - min) / size;
+                // Texture V runs top to bottom
+                [uv.x, 1.0 - uv.y]
+            })
+            .collect();
+    }
+}
+
+/// Collects a glyph outline as flattened closed contours.
+struct ContourBuilder {
+    contours: Vec<Vec<Vec2>>,
+    current: Vec<Vec2>,
+    scale: f32,
+    segments: u32,
+}
+
+impl ContourBuilder {
+    fn last(&self) -> Vec2 {
+        self.current.last().copied().unwrap_or(Vec2::ZERO)
+    }
+
+    fn flush(&mut self) {
+        let mut contour = std::mem::take(&mut self.current);
+
+        // Closed contours often repeat their starting point
+        if contour.len() > 1 && contour.first() == contour.last() {
+            contour.pop();
+        }
+        if contour.len() >= 3 {
+            self.contours.push(contour);
+        }
+    }
+}
+
+impl OutlineBuilder for ContourBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.flush();
+        self.current.push(Vec2::new(x, y) * self.scale);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.current.push(Vec2::new(x, y) * self.scale);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.last();
+        let p1 = Vec2::new(x1, y1) * self.scale;
+        let p2 = Vec2::new(x, y) * self.scale;
+
+        for i in 1..=self.segments {
+            let t = i as f32 / self.segments as f32;
+            let mt = 1.0 - t;
+            self.current
+                .push(p0 * (mt * mt) + p1 * (2.0 * mt * t) + p2 * (t * t));
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = self.last();
+        let p1 = Vec2::new(x1, y1) * self.scale;
+        let p2 = Vec2::new(x2, y2) * self.scale;
+        let p3 = Vec2::new(x, y) * self.scale;
+
+        for i in 1..=self.segments {
+            let t = i as f32 / self.segments as f32;
+            let mt = 1.0 - t;
+            self.current.push(
+                p0 * (mt * mt * mt)
+                    + p1 * (3.0 * mt * mt * t)
+                    + p2 * (3.0 * mt * t * t)
+                    + p3 * (t * t * t),
+            );
+        }
+    }
+
+    fn close(&mut self) {
+        self.flush();
+    }
+}
+
+/// Signed area of a closed contour (positive when counter-clockwise).
+pub(crate) fn signed_area(contour: &[Vec2]) -> f32 {
+    contour
+        .iter()
+        .zip(contour.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum::<f32>()
+        * 0.5
+}
+
+/// Advance of the `.notdef` glyph in em units.
+///
+/// Falls back to half an em for fonts that give `.notdef` no advance.
+pub(crate) fn notdef_advance(face: &fontmesh::Face) -> f32 {
+    face.glyph_hor_advance(GlyphId(0))
+        .filter(|&advance| advance > 0)
+        .map_or(0.5, |advance| advance as f32 / face.units_per_em() as f32)
+}
+
+/// Hollow box contours standing in for a `.notdef` glyph the font doesn't draw.
+fn notdef_box(face: &fontmesh::Face) -> Vec<Vec<Vec2>> {
+    let advance = notdef_advance(face);
+    let height = face
+        .capital_height()
+        .filter(|&height| height > 0)
+        .map_or(fontmesh::ascender(face) * 0.7, |height| {
+            height as f32 / face.units_per_em() as f32
+        });
+    let stroke = advance * 0.1;
+
+    let rect =
+        |min: Vec2, max: Vec2| vec![min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)];
+    let outer_min = Vec2::new(stroke, 0.0);
+    let outer_max = Vec2::new(advance - stroke, height);
+    let mut inner = rect(outer_min + stroke, outer_max - stroke);
+    // Holes run clockwise
+    inner.reverse();
+
+    vec![rect(outer_min, outer_max), inner]
+}
+
+/// Extract the flattened contours of a glyph, in em units.
+///
+/// Each curve is split into `subdivision` line segments. Contours are oriented so
+/// that filled regions lie to the left (outer contours counter-clockwise, holes
+/// clockwise) regardless of the font's native convention. A `.notdef` glyph
+/// without an outline is replaced by a hollow box.
+pub(crate) fn glyph_contours(
+    face: &fontmesh::Face,
+    glyph_id: GlyphId,
+    subdivision: u8,
+) -> Option<Vec<Vec<Vec2>>> {
+    let mut builder = ContourBuilder {
+        contours: Vec::new(),
+        current: Vec::new(),
+        scale: 1.0 / face.units_per_em() as f32,
+        segments: subdivision.max(1) as u32,
+    };
+    let outlined = face.outline_glyph(glyph_id, &mut builder).is_some();
+    builder.flush();
+
+    let mut contours = builder.contours;
+    if !outlined || contours.is_empty() {
+        return (glyph_id.0 == 0).then(|| notdef_box(face));
+    }
+
+    // TrueType outlines run clockwise, CFF counter-clockwise
+    let total_area: f32 = contours.iter().map(|c| signed_area(c)).sum();
+    if total_area < 0.0 {
+        contours.iter_mut().for_each(|c| c.reverse());
+    }
+
+    Some(contours)
+}
+
+/// Triangulate the filled area of a set of contours.
+///
+/// Returns 2D vertices and counter-clockwise triangle indices.
+fn tessellate(contours: &[Vec<Vec2>]) -> Option<(Vec<Vec2>, Vec<u32>)> {
+    let mut path = Path::builder();
+    for contour in contours {
+        path.begin(point(contour[0].x, contour[0].y));
+        for p in &contour[1..] {
+            path.line_to(point(p.x, p.y));
+        }
+        path.end(true);
+    }
+    let path = path.build();
+
+    let mut buffers: VertexBuffers<Vec2, u32> = VertexBuffers::new();
+    FillTessellator::new()
+        .tessellate_path(
+            &path,
+            &FillOptions::non_zero(),
+            &mut BuffersBuilder::new(&mut buffers, |v: FillVertex| {
+                Vec2::new(v.position().x, v.position().y)
+            }),
+        )
+        .ok()?;
+
+    // Make every triangle counter-clockwise so it faces +Z
+    for tri in buffers.indices.chunks_exact_mut(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| buffers.vertices[tri[i] as usize]);
+        if (b - a).perp_dot(c - a) < 0.0 {
+            tri.swap(1, 2);
+        }
+    }
+
+    Some((buffers.vertices, buffers.indices))
+}
+
+/// One segment of the cross-section swept along each contour.
+///
+/// Offsets are measured outward from the glyph outline and `z` runs from the
+/// front face (`0`) towards the back. Normals are `(outward, z)` pairs.
+#[derive(Clone, Copy, Debug)]
+struct ProfileSegment {
+    start: Vec2,
+    end: Vec2,
+    start_normal: Vec2,
+    end_normal: Vec2,
+}
+
+/// Build the cross-section profile from the front face to the back face.
+fn extrusion_profile(depth: f32, bevel: Option<TextBevel>) -> Vec<ProfileSegment> {
+    let side = |from: f32, to: f32| ProfileSegment {
+        start: Vec2::new(0.0, from),
+        end: Vec2::new(0.0, to),
+        start_normal: Vec2::X,
+        end_normal: Vec2::X,
+    };
+
+    let Some(bevel) = bevel.filter(|b| b.width > 0.0 && b.depth > 0.0) else {
+        return vec![side(0.0, -depth)];
+    };
+
+    let width = bevel.width;
+    let bevel_depth = bevel.depth.min(depth * 0.5);
+    let segments = bevel.segments.max(1) as usize;
+
+    // Quarter-circle from the inset front face out to the side wall
+    let point = |angle: f32| {
+        Vec2::new(
+            -width * (1.0 - angle.sin()),
+            -bevel_depth * (1.0 - angle.cos()),
+        )
+    };
+    let normal = |angle: f32| {
+        if segments == 1 {
+            // Flat chamfer
+            Vec2::new(bevel_depth, width).normalize()
+        } else {
+            Vec2::new(bevel_depth * angle.sin(), width * angle.cos()).normalize()
+        }
+    };
+
+    let front: Vec<ProfileSegment> = (0..segments)
+        .map(|i| {
+            let a0 = i as f32 / segments as f32 * std::f32::consts::FRAC_PI_2;
+            let a1 = (i + 1) as f32 / segments as f32 * std::f32::consts::FRAC_PI_2;
+            ProfileSegment {
+                start: point(a0),
+                end: point(a1),
+                start_normal: normal(a0),
+                end_normal: normal(a1),
+            }
+        })
+        .collect();
+
+    // The back bevel mirrors the front one
+    let mirror = |v: Vec2| Vec2::new(v.x, -depth - v.y);
+    let mirror_normal = |n: Vec2| Vec2::new(n.x, -n.y);
+    let back = front.iter().rev().map(|segment| ProfileSegment {
+        start: mirror(segment.end),
+        end: mirror(segment.start),
+        start_normal: mirror_normal(segment.end_normal),
+        end_normal: mirror_normal(segment.start_normal),
+    });
+
+    let mut profile = front.clone();
+    if depth > bevel_depth * 2.0 {
+        profile.push(side(-bevel_depth, bevel_depth - depth));
+    }
+    profile.extend(back);
+    profile
+}
+
+/// Offset a contour outward by `distance` (negative values inset it).
+///
+/// Corners are mitered, with the miter length limited to avoid spikes at sharp points.
+fn offset_contour(contour: &[Vec2], distance: f32) -> Vec<Vec2> {
+    const MITER_LIMIT: f32 = 4.0;
+
+    let normals = edge_normals(contour);
+    let count = contour.len();
+
+    (0..count)
+        .map(|i| {
+            let previous = normals[(i + count - 1) % count];
+            let next = normals[i];
+            let denominator = 1.0 + previous.dot(next);
+            let miter = if denominator > 1e-4 {
+                ((previous + next) / denominator).clamp_length_max(MITER_LIMIT)
+            } else {
+                next
+            };
+            contour[i] + miter * distance
+        })
+        .collect()
+}
+
+/// Outward unit normal of each contour edge (edge `i` runs from point `i` to `i + 1`).
+fn edge_normals(contour: &[Vec2]) -> Vec<Vec2> {
+    let count = contour.len();
+    (0..count)
+        .map(|i| {
+            let edge = contour[(i + 1) % count] - contour[i];
+            // Filled area is on the left, so the outward normal points right
+            Vec2::new(edge.y, -edge.x).normalize_or_zero()
+        })
+        .collect()
+}
+
+/// Replace every contour by a band of `width` centered on it.
+///
+/// The outer edge keeps the contour's orientation and the inner edge is reversed,
+/// so the band is filled for outer contours and holes alike.
+fn stroke_contours(contours: &[Vec<Vec2>], width: f32) -> Vec<Vec<Vec2>> {
+    contours
+        .iter()
+        .flat_map(|contour| {
+            let outer = offset_contour(contour, width * 0.5);
+            let mut inner = offset_contour(contour, -width * 0.5);
+            inner.reverse();
+            [outer, inner]
+        })
+        .collect()
+}
+
+/// Generate an extruded mesh for a glyph.
+///
+/// The outline is scaled by the style's font size; the front face lies at `z = 0`
+/// facing +Z and the back face at `z = -depth`.
+/// With a stroke fill, a band around each contour is extruded instead of its area.
+/// With a bevel, the front and back faces are inset by the bevel width and joined
+/// to the side walls by a chamfered or rounded strip. Flat extrusion modes only
+/// emit the front face, and optionally a back face at the same position.
+/// Returns `None` for glyphs without an outline (e.g. spaces).
+pub(crate) fn glyph_geometry(
+    face: &fontmesh::Face,
+    glyph_id: GlyphId,
+    style: &TextMeshStyle,
+) -> Option<GlyphGeometry> {
+    let depth = style.depth;
+    let mut contours = glyph_contours(face, glyph_id, style.subdivision)?;
+    for point in contours.iter_mut().flatten() {
+        *point *= style.font_size;
+    }
+    if let TextFill::Stroke { width } = style.fill {
+        if width > 0.0 {
+            contours = stroke_contours(&contours, width);
+        }
+    }
+    if style.extrusion != TextExtrusion::Extruded {
+        return flat_geometry(&contours, style.extrusion);
+    }
+    let profile = extrusion_profile(depth, style.bevel);
+
+    // Caps sit at the inner end of the profile
+    let cap_offset = profile[0].start.x;
+    let cap_contours: Vec<Vec<Vec2>> = if cap_offset != 0.0 {
+        contours
+            .iter()
+            .map(|c| offset_contour(c, cap_offset))
+            .collect()
+    } else {
+        contours.clone()
+    };
+    let (cap_vertices, cap_indices) = tessellate(&cap_contours)?;
+
+    let mut geometry = GlyphGeometry::default();
+
+    // Front face
+    geometry
+        .vertices
+        .extend(cap_vertices.iter().map(|v| [v.x, v.y, 0.0]));
+    geometry
+        .normals
+        .extend(std::iter::repeat_n([0.0, 0.0, 1.0], cap_vertices.len()));
+    geometry
+        .uvs
+        .extend(cap_vertices.iter().map(|v| [v.x, -v.y]));
+    geometry.indices.extend(&cap_indices);
+
+    // Back face (reversed winding)
+    let back_offset = geometry.vertices.len() as u32;
+    geometry
+        .vertices
+        .extend(cap_vertices.iter().map(|v| [v.x, v.y, -depth]));
+    geometry
+        .normals
+        .extend(std::iter::repeat_n([0.0, 0.0, -1.0], cap_vertices.len()));
+    geometry
+        .uvs
+        .extend(cap_vertices.iter().map(|v| [-v.x, -v.y]));
+    geometry.indices.extend(
+        cap_indices
+            .chunks_exact(3)
+            .flat_map(|tri| [tri[0], tri[2], tri[1]])
+            .map(|i| i + back_offset),
+    );
+
+    // Side walls and bevels
+    for contour in &contours {
+        sweep_contour(&mut geometry, contour, &profile);
+    }
+
+    Some(geometry)
+}
+
+/// Generate the faces of a flat glyph at `z = 0`.
+fn flat_geometry(contours: &[Vec<Vec2>], extrusion: TextExtrusion) -> Option<GlyphGeometry> {
+    let (vertices, indices) = tessellate(contours)?;
+    let mut geometry = GlyphGeometry {
+        vertices: vertices.iter().map(|v| [v.x, v.y, 0.0]).collect(),
+        normals: vec![[0.0, 0.0, 1.0]; vertices.len()],
+        uvs: vertices.iter().map(|v| [v.x, -v.y]).collect(),
+        indices: indices.clone(),
+    };
+
+    if extrusion == TextExtrusion::FlatDoubleSided {
+        // Back face shares the positions but has its own normals and mirrored UVs
+        let back_offset = vertices.len() as u32;
+        geometry
+            .vertices
+            .extend(vertices.iter().map(|v| [v.x, v.y, 0.0]));
+        geometry
+            .normals
+            .extend(std::iter::repeat_n([0.0, 0.0, -1.0], vertices.len()));
+        geometry.uvs.extend(vertices.iter().map(|v| [-v.x, -v.y]));
+        geometry.indices.extend(
+            indices
+                .chunks_exact(3)
+                .flat_map(|tri| [tri[0], tri[2], tri[1]])
+                .map(|i| i + back_offset),
+        );
+    }
+
+    Some(geometry)
+}
+
+/// Append the profile swept along one contour.
+///
+/// Side UVs are unwrapped with U running along the outline length and V along
+/// the profile from the front face.
+fn sweep_contour(geometry: &mut GlyphGeometry, contour: &[Vec2], profile: &[ProfileSegment]) {
+    let count = contour.len();
+    let normals = edge_normals(contour);
+
+    // Distance along the outline at each point (with the closing point at the end)
+    let outline_distance: Vec<f32> = std::iter::once(0.0)
+        .chain((0..count).scan(0.0, |distance, i| {
+            *distance += contour[i].distance(contour[(i + 1) % count]);
+            Some(*distance)
+        }))
+        .collect();
+    let mut profile_distance = 0.0;
+
+    let smooth = |normal: Vec2, neighbour: Vec2| {
+        if normal.dot(neighbour) > SMOOTHING_ANGLE_COS {
+            (normal + neighbour).normalize_or_zero()
+        } else {
+            normal
+        }
+    };
+
+    // Offset rings for every distinct profile offset
+    let mut rings: Vec<(f32, Vec<Vec2>)> = Vec::new();
+    let mut ring = |offset: f32| -> Vec<Vec2> {
+        if let Some((_, ring)) = rings.iter().find(|(o, _)| *o == offset) {
+            return ring.clone();
+        }
+        let ring = if offset == 0.0 {
+            contour.to_vec()
+        } else {
+            offset_contour(contour, offset)
+        };
+        rings.push((offset, ring.clone()));
+        ring
+    };
+
+    for segment in profile {
+        let front = ring(segment.start.x);
+        let back = ring(segment.end.x);
+        let v0 = profile_distance;
+        let v1 = v0 + segment.start.distance(segment.end);
+        profile_distance = v1;
+        let to_3d = |n: Vec2, profile_normal: Vec2| {
+            Vec3::new(
+                n.x * profile_normal.x,
+                n.y * profile_normal.x,
+                profile_normal.y,
+            )
+            .normalize_or_zero()
+            .to_array()
+        };
+
+        for i in 0..count {
+            let j = (i + 1) % count;
+            let normal = normals[i];
+            let normal_a = smooth(normal, normals[(i + count - 1) % count]);
+            let normal_b = smooth(normal, normals[j]);
+
+            let base = geometry.vertices.len() as u32;
+            geometry.vertices.extend([
+                [front[i].x, front[i].y, segment.start.y],
+                [back[i].x, back[i].y, segment.end.y],
+                [front[j].x, front[j].y, segment.start.y],
+                [back[j].x, back[j].y, segment.end.y],
+            ]);
+            geometry.normals.extend([
+                to_3d(normal_a, segment.start_normal),
+                to_3d(normal_a, segment.end_normal),
+                to_3d(normal_b, segment.start_normal),
+                to_3d(normal_b, segment.end_normal),
+            ]);
+            let (u0, u1) = (outline_distance[i], outline_distance[i + 1]);
+            geometry
+                .uvs
+                .extend([[u0, v0], [u0, v1], [u1, v0], [u1, v1]]);
+            geometry
+                .indices
+                .extend([base, base + 1, base + 2, base + 2, base + 1, base + 3]);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Placement of text along a [`TextMeshPath`].
+//!
+//! Paths are flattened into a polyline once per generation. Straight text is then
+//! mapped onto it: x becomes the distance along the path and y the offset to its
+//! left, so upright text follows the path in its direction of travel.
+
+use crate::component::TextMeshPath;
+use bevy::math::cubic_splines::{CubicBezier, CubicCardinalSpline, CubicCurve, CubicGenerator};
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+/// Points sampled per segment when flattening splines
+const SPLINE_SAMPLES: usize = 16;
+
+/// Maximum angle between two points when flattening arcs
+const ARC_STEP: f32 = PI / 90.0;
+
+/// A path flattened into a polyline, sampled by distance along it.
+pub(crate) struct PathSampler {
+    points: Vec<Vec2>,
+    /// Distance from the start of the path to each point
+    distances: Vec<f32>,
+    /// Unit tangent at each point, averaged over its two segments
+    tangents: Vec<Vec2>,
+}
+
+impl PathSampler {
+    /// Flatten a path. Returns `None` if it has no length.
+    pub(crate) fn new(path: &TextMeshPath) -> Option<Self> {
+        let points = match path {
+            TextMeshPath::Arc {
+                radius,
+                start_angle,
+                end_angle,
+            } => {
+                let sweep = end_angle - start_angle;
+                let steps = ((sweep.abs() / ARC_STEP).ceil() as usize).max(1);
+                (0..=steps)
+                    .map(|step| Vec2::from_angle(start_angle + sweep * step as f32 / steps as f32))
+                    .map(|direction| direction * *radius)
+                    .collect()
+            }
+            TextMeshPath::Bezier(segments) => {
+                sample_curve(&CubicBezier::new(segments.iter().copied()).to_curve().ok()?)
+            }
+            TextMeshPath::CatmullRom(points) => sample_curve(
+                &CubicCardinalSpline::new_catmull_rom(points.iter().copied())
+                    .to_curve()
+                    .ok()?,
+            ),
+            TextMeshPath::Polyline(points) => points.clone(),
+        };
+
+        let mut sampler = Self {
+            points: Vec::with_capacity(points.len()),
+            distances: Vec::with_capacity(points.len()),
+            tangents: Vec::new(),
+        };
+        for point in points {
+            let distance = match sampler.points.last() {
+                Some(last) => {
+                    let step = last.distance(point);
+                    // Skip repeated points, which have no direction
+                    if step <= 0.0 {
+                        continue;
+                    }
+                    sampler.length() + step
+                }
+                None => 0.0,
+            };
+            sampler.points.push(point);
+            sampler.distances.push(distance);
+        }
+
+        if sampler.points.len() < 2 {
+            return None;
+        }
+
+        // Interpolating tangents between points keeps bent text continuous at corners
+        let directions: Vec<Vec2> = sampler
+            .points
+            .windows(2)
+            .map(|segment| (segment[1] - segment[0]).normalize())
+            .collect();
+        sampler.tangents = (0..sampler.points.len())
+            .map(|point| {
+                let before = directions[point.saturating_sub(1)];
+                let after = directions[point.min(directions.len() - 1)];
+                (before + after).try_normalize().unwrap_or(after)
+            })
+            .collect();
+
+        Some(sampler)
+    }
+
+    /// Total length of the path.
+    pub(crate) fn length(&self) -> f32 {
+        self.distances[self.distances.len() - 1]
+    }
+
+    /// Position and unit tangent at a distance along the path.
+    ///
+    /// Before its start and after its end, the path continues in a straight line.
+    pub(crate) fn sample(&self, distance: f32) -> (Vec2, Vec2) {
+        let segment = self
+            .distances
+            .partition_point(|&d| d <= distance)
+            .clamp(1, self.points.len() - 1)
+            - 1;
+        let (start, end) = (self.points[segment], self.points[segment + 1]);
+        let length = self.distances[segment + 1] - self.distances[segment];
+        let t = (distance - self.distances[segment]) / length;
+
+        let tangent = self.tangents[segment]
+            .lerp(self.tangents[segment + 1], t.clamp(0.0, 1.0))
+            .normalize();
+        (start.lerp(end, t), tangent)
+    }
+
+    /// Map a point of straight text onto the path.
+    pub(crate) fn map_point(&self, point: Vec2) -> Vec2 {
+        let (position, tangent) = self.sample(point.x);
+        position + tangent.perp() * point.y
+    }
+
+    /// Bend a vertex of straight text onto the path, rotating its normal to match.
+    pub(crate) fn bend(&self, position: Vec3, normal: Vec3) -> (Vec3, Vec3) {
+        let (_, tangent) = self.sample(position.x);
+        (
+            self.map_point(position.truncate()).extend(position.z),
+            tangent.rotate(normal.truncate()).extend(normal.z),
+        )
+    }
+
+    /// Place a rigid glyph of straight text on the path.
+    ///
+    /// `origin` is the glyph's position in the straight text and `center` the
+    /// horizontal center of its outline relative to that origin. The glyph is
+    /// rotated to the tangent at its center. Returns the new origin and rotation.
+    pub(crate) fn place(&self, origin: Vec2, center: f32) -> (Vec2, Quat) {
+        let (point, tangent) = self.sample(origin.x + center);
+        let position = point + tangent.perp() * origin.y - tangent * center;
+        (position, Quat::from_rotation_z(tangent.to_angle()))
+    }
+}
+
+/// Sample a cubic curve evenly in its parameter
+fn sample_curve(curve: &CubicCurve<Vec2>) -> Vec<Vec2> {
+    curve
+        .iter_positions(curve.segments().len() * SPLINE_SAMPLES)
+        .collect()
+}
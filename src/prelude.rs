@@ -1,9 +1,16 @@
 pub use crate::{
-    asset::{FontMesh, FontMetrics, GlyphMetrics},
+    asset::{FontMesh, FontMetrics, GlyphContour, GlyphMetrics},
+    cache::GlyphMeshCache,
     component::{
-        GlyphMesh, JustifyText, TextAnchor, TextMesh, TextMeshBundle, TextMeshGlyphs,
-        TextMeshGlyphsBundle, TextMeshStyle,
+        GlyphMesh, JustifyText, SpanMesh, TextAnchor, TextBevel, TextDeform, TextDirection,
+        TextExtrusion, TextFill, TextMesh, TextMeshBundle, TextMeshGlyphs, TextMeshGlyphsBundle,
+        TextMeshPath, TextMeshSpan, TextMeshSpans, TextMeshSpansBundle, TextMeshStyle,
+        TextUvMapping, TextWrap, WritingMode,
     },
-    system::{generate_glyph_mesh, TextMeshComputed, TextMeshGlyphsComputed},
-    FontMeshPlugin,
+    settings::FontMeshSettings,
+    system::{
+        generate_glyph_id_mesh, generate_glyph_mesh, generate_glyph_mesh_with_style, MissingGlyphs,
+        TextMeshComputed, TextMeshGlyphsComputed, TextMeshSpansComputed,
+    },
+    FontMeshGlyphsPlugin, FontMeshPlugin,
 };
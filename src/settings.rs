@@ -0,0 +1,71 @@
+//! Global configuration for text mesh generation.
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Global settings for text mesh generation.
+///
+/// Insert this resource to override the defaults used by
+/// [`FontMeshPlugin`](crate::FontMeshPlugin).
+///
+/// # Generation Budget
+///
+/// By default every changed text entity is processed in the frame it changes.
+/// Setting [`max_glyphs_per_frame`](Self::max_glyphs_per_frame) or
+/// [`max_time_per_frame`](Self::max_time_per_frame) spreads bulk changes over
+/// several frames instead. Entities visible in the last frame are processed first,
+/// and at least one entity is processed every frame so large texts never stall.
+/// With [`async_generation`](Self::async_generation) the budget limits how much
+/// work is dispatched to background tasks each frame, and the time limit also
+/// covers applying finished tasks, of which at least one is applied every frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::FontMeshSettings;
+/// // Generate meshes in the same frame, e.g. for tests or deterministic captures
+/// let mut app = App::new();
+/// app.insert_resource(FontMeshSettings {
+///     async_generation: false,
+///     ..default()
+/// });
+///
+/// // Spend at most 2ms per frame on text, e.g. when swapping the language of a UI
+/// app.insert_resource(FontMeshSettings {
+///     max_time_per_frame: Some(std::time::Duration::from_millis(2)),
+///     ..default()
+/// });
+/// ```
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct FontMeshSettings {
+    /// Whether meshes are generated in background tasks on the `AsyncComputeTaskPool`.
+    ///
+    /// When enabled, changed text keeps its previous mesh until the new one is
+    /// ready. When disabled, meshes are generated synchronously in the same frame
+    /// the text changes. Default: `true`.
+    pub async_generation: bool,
+
+    /// Maximum number of glyphs to generate per frame, shared by all text entities.
+    ///
+    /// Entities whose text would exceed the budget are deferred to a later frame.
+    /// Default: `None` (unlimited).
+    pub max_glyphs_per_frame: Option<usize>,
+
+    /// Maximum time to spend generating text per frame.
+    ///
+    /// Once exceeded, remaining entities are deferred to a later frame.
+    /// Default: `None` (unlimited).
+    pub max_time_per_frame: Option<Duration>,
+}
+
+impl Default for FontMeshSettings {
+    fn default() -> Self {
+        Self {
+            async_generation: true,
+            max_glyphs_per_frame: None,
+            max_time_per_frame: None,
+        }
+    }
+}
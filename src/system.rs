@@ -1,79 +1,370 @@
-use crate::component::{GlyphMesh, JustifyText, TextAnchor, TextMesh, TextMeshGlyphs};
+use crate::asset::FontFace;
+use crate::cache::{CachedFont, GlyphMeshCache};
+use crate::component::{
+    GlyphMesh, SpanMesh, TextMesh, TextMeshGlyphs, TextMeshSpans, TextMeshStyle, TextUvMapping,
+};
+use crate::deform::deform_geometry;
+use crate::layout::{layout_offset, layout_runs, LayoutGlyph, TextRun};
+use crate::outline::{glyph_geometry, GlyphGeometry};
+use crate::path::PathSampler;
+use crate::settings::FontMeshSettings;
 use crate::FontMesh;
 use bevy::asset::RenderAssetUsages;
+use bevy::ecs::system::SystemParam;
 use bevy::mesh::Indices;
+use bevy::platform::collections::HashSet;
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
 use bevy::render::render_resource::PrimitiveTopology;
+use bevy::tasks::{futures::check_ready, AsyncComputeTaskPool, Task};
+use std::sync::Arc;
+use std::time::Duration;
 
-/// Helper function to calculate the width of a line of text
-#[inline]
-fn calculate_line_width(line: &str, face: &fontmesh::Face) -> f32 {
-    line.chars().map(|ch| get_glyph_advance(ch, face)).sum()
+/// Helper function to create a Bevy mesh from vertex/normal/uv/index data
+///
+/// Tangents are generated from the UVs when the style requests them.
+fn create_mesh_from_data(
+    vertices: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+    style: &TextMeshStyle,
+) -> Mesh {
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+
+    if style.generate_tangents {
+        // Positions, normals, UVs and a triangle list are always present, so this
+        // only fails on degenerate input; the mesh is still usable without tangents
+        mesh.generate_tangents().ok();
+    }
+
+    mesh
 }
 
-/// Helper function to get the advance width for a character
-#[inline]
-fn get_glyph_advance(ch: char, face: &fontmesh::Face) -> f32 {
-    fontmesh::glyph_advance(face, ch).unwrap_or_else(|| {
-        if ch.is_whitespace() {
-            // Use font metrics for a proportional fallback space width
-            // Typically ~25% of the font height is a good space width
-            (fontmesh::ascender(face) - fontmesh::descender(face)) * 0.25
-        } else {
-            0.0
+/// Helper function to generate the geometry of every glyph in a layout.
+///
+/// Glyph geometry is looked up in the [`GlyphMeshCache`] first, using the font the
+/// glyph was shaped with and the style of its run. `fonts` holds the cached fonts of
+/// each run's faces. Glyphs without an outline (e.g. spaces) are skipped.
+/// UVs are mapped according to the block style's [`TextMeshStyle::uv_mapping`];
+/// geometry stays in glyph-local coordinates.
+fn generate_layout_geometry(
+    cache: &GlyphMeshCache,
+    fonts: &[Vec<CachedFont>],
+    runs: &[TextRun],
+    layout: &[LayoutGlyph],
+    style: &TextMeshStyle,
+) -> Vec<(LayoutGlyph, GlyphGeometry)> {
+    let mut glyphs: Vec<(LayoutGlyph, GlyphGeometry)> = layout
+        .iter()
+        .filter_map(|glyph| {
+            let run = &runs[glyph.run_index];
+            let geometry = cache.glyph_geometry(
+                fonts[glyph.run_index][glyph.face_index],
+                run.faces[glyph.face_index],
+                glyph.glyph_id,
+                run.style,
+            )?;
+            Some((*glyph, geometry))
+        })
+        .collect();
+
+    match style.uv_mapping {
+        TextUvMapping::Glyph => {
+            for (_, geometry) in &mut glyphs {
+                let (min, max) = geometry.bounds();
+                geometry.project_uvs(min, max, Vec2::ZERO);
+            }
         }
-    })
+        TextUvMapping::Text => {
+            let (min, max) = glyphs.iter().fold(
+                (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+                |(min, max), (glyph, geometry)| {
+                    let (glyph_min, glyph_max) = geometry.bounds();
+                    (
+                        min.min(glyph_min + glyph.position),
+                        max.max(glyph_max + glyph.position),
+                    )
+                },
+            );
+            for (glyph, geometry) in &mut glyphs {
+                geometry.project_uvs(min, max, glyph.position);
+            }
+        }
+        TextUvMapping::Unwrap => {}
+    }
+
+    glyphs
 }
 
-/// Helper function to calculate the X offset based on text justification
-#[inline]
-fn calculate_justification_offset(justify: JustifyText, line_width: f32) -> f32 {
-    match justify {
-        JustifyText::Left => 0.0,
-        JustifyText::Center => -line_width * 0.5,
-        JustifyText::Right => -line_width,
-    }
+/// Generated meshes of a text entity, with the characters no font had a glyph for.
+struct Generated<T> {
+    meshes: T,
+    missing: Vec<char>,
 }
 
-/// Helper function to calculate anchor offset for text positioning
-fn calculate_anchor_offset(anchor: TextAnchor, min_bound: Vec3, max_bound: Vec3) -> Vec3 {
-    let size = max_bound - min_bound;
-    let center = min_bound + size * 0.5;
+/// Generate one combined mesh per run of a text block.
+///
+/// All runs are laid out as one flowing block and anchored by their common bounds,
+/// then bent onto the style's path if it has one and deformed by its modifiers.
+fn build_run_meshes(
+    cache: &GlyphMeshCache,
+    fonts: &[Vec<CachedFont>],
+    runs: &[TextRun],
+    style: &TextMeshStyle,
+) -> Generated<Vec<Mesh>> {
+    // 1. Shape and lay out the text
+    let layout = layout_runs(runs, style);
+
+    // 2. Generate combined mesh data per run
+    let mut buffers = vec![GlyphGeometry::default(); runs.len()];
+
+    // Bounds tracking
+    let mut min_bound = Vec3::splat(f32::MAX);
+    let mut max_bound = Vec3::splat(f32::MIN);
 
-    match anchor {
-        TextAnchor::TopLeft => Vec3::new(-min_bound.x, -max_bound.y, 0.0),
-        TextAnchor::TopCenter => Vec3::new(-center.x, -max_bound.y, 0.0),
-        TextAnchor::TopRight => Vec3::new(-max_bound.x, -max_bound.y, 0.0),
+    for (glyph, mesh) in generate_layout_geometry(cache, fonts, runs, &layout.glyphs, style) {
+        let buffer = &mut buffers[glyph.run_index];
+        let index_offset = buffer.vertices.len() as u32;
 
-        TextAnchor::CenterLeft => Vec3::new(-min_bound.x, -center.y, 0.0),
-        TextAnchor::Center => Vec3::new(-center.x, -center.y, 0.0),
-        TextAnchor::CenterRight => Vec3::new(-max_bound.x, -center.y, 0.0),
+        // Extend vertices and update bounds
+        buffer.vertices.extend(mesh.vertices.iter().map(|v| {
+            let pos = Vec3::new(v[0] + glyph.position.x, v[1] + glyph.position.y, v[2]);
+            min_bound = min_bound.min(pos);
+            max_bound = max_bound.max(pos);
+            [pos.x, pos.y, pos.z]
+        }));
 
-        TextAnchor::BottomLeft => Vec3::new(-min_bound.x, -min_bound.y, 0.0),
-        TextAnchor::BottomCenter => Vec3::new(-center.x, -min_bound.y, 0.0),
-        TextAnchor::BottomRight => Vec3::new(-max_bound.x, -min_bound.y, 0.0),
+        // Extend normals and UVs
+        buffer.normals.extend(mesh.normals);
+        buffer.uvs.extend(mesh.uvs);
+
+        // Extend indices with offset
+        buffer
+            .indices
+            .extend(mesh.indices.iter().map(|i| i + index_offset));
+    }
 
-        TextAnchor::Custom(pivot) => {
-            let pivot_pos = min_bound.truncate() + size.truncate() * pivot;
-            Vec3::new(-pivot_pos.x, -pivot_pos.y, 0.0)
+    // 3. Apply Anchor Offset and bend onto the path
+    if min_bound.x <= max_bound.x {
+        let path = style.path.as_ref().and_then(PathSampler::new);
+        let offset = layout_offset(style, path.as_ref(), min_bound, max_bound);
+        for buffer in &mut buffers {
+            buffer.vertices.iter_mut().for_each(|v| {
+                v[0] += offset.x;
+                v[1] += offset.y;
+                v[2] += offset.z;
+            });
+            if let Some(path) = &path {
+                for (vertex, normal) in buffer.vertices.iter_mut().zip(&mut buffer.normals) {
+                    let (bent, bent_normal) = path.bend(Vec3::from(*vertex), Vec3::from(*normal));
+                    *vertex = bent.into();
+                    *normal = bent_normal.into();
+                }
+            }
         }
     }
+
+    // 4. Apply deformation modifiers
+    deform_geometry(&style.deform, &mut buffers);
+
+    // 5. Create Bevy Meshes
+    let meshes = buffers
+        .into_iter()
+        .map(|buffer| {
+            create_mesh_from_data(
+                buffer.vertices,
+                buffer.normals,
+                buffer.uvs,
+                buffer.indices,
+                style,
+            )
+        })
+        .collect();
+
+    Generated {
+        meshes,
+        missing: layout.missing,
+    }
 }
 
-/// Helper function to create a Bevy mesh from vertex/normal/index data
-fn create_mesh_from_data(
-    vertices: Vec<[f32; 3]>,
-    normals: Vec<[f32; 3]>,
-    indices: Vec<u32>,
-) -> Mesh {
-    let mut mesh = Mesh::new(
-        PrimitiveTopology::TriangleList,
-        RenderAssetUsages::default(),
-    );
-    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-    mesh.insert_indices(Indices::U32(indices));
-    mesh
+/// Shared parsed face of a font, for use in background tasks.
+struct FontSource {
+    /// Cache generation the face was read in
+    font: CachedFont,
+    /// `None` if the font data is invalid
+    face: Option<Arc<FontFace>>,
+}
+
+/// Faces of a font and its fallbacks for shaping.
+///
+/// Fallback fonts with invalid data are skipped. Returns `None` if the primary
+/// font is invalid.
+fn source_faces(sources: &[FontSource]) -> Option<(Vec<CachedFont>, Vec<&rustybuzz::Face<'_>>)> {
+    let mut fonts = Vec::with_capacity(sources.len());
+    let mut faces = Vec::with_capacity(sources.len());
+
+    for (index, source) in sources.iter().enumerate() {
+        match &source.face {
+            Some(face) => {
+                fonts.push(source.font);
+                faces.push(face.face());
+            }
+            None if index == 0 => return None,
+            None => {}
+        }
+    }
+    Some((fonts, faces))
+}
+
+/// Generate the combined mesh for a text block.
+///
+/// Returns `None` if the font data cannot be parsed.
+fn build_text_mesh(
+    cache: &GlyphMeshCache,
+    fonts: &[FontSource],
+    text: &str,
+    style: &TextMeshStyle,
+) -> Option<Generated<Mesh>> {
+    // Shaping faces are parsed once per font (glyph geometry is cached separately)
+    let (ids, faces) = source_faces(fonts)?;
+    let runs = [TextRun {
+        faces: &faces,
+        text,
+        style,
+    }];
+    let Generated {
+        mut meshes,
+        missing,
+    } = build_run_meshes(cache, &[ids], &runs, style);
+    Some(Generated {
+        meshes: meshes.pop()?,
+        missing,
+    })
+}
+
+/// Owned inputs for generating one span of a [`TextMeshSpans`].
+struct SpanSource {
+    fonts: Vec<FontSource>,
+    text: String,
+    style: TextMeshStyle,
+}
+
+/// Generate one mesh per span of a rich text block.
+///
+/// Returns `None` if the data of any span's font cannot be parsed.
+fn build_span_meshes(
+    cache: &GlyphMeshCache,
+    sources: &[SpanSource],
+    style: &TextMeshStyle,
+) -> Option<Generated<Vec<Mesh>>> {
+    let (fonts, faces): (Vec<_>, Vec<_>) = sources
+        .iter()
+        .map(|source| source_faces(&source.fonts))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+    let runs: Vec<TextRun> = sources
+        .iter()
+        .zip(&faces)
+        .map(|(source, faces)| TextRun {
+            faces,
+            text: &source.text,
+            style: &source.style,
+        })
+        .collect();
+
+    Some(build_run_meshes(cache, &fonts, &runs, style))
+}
+
+/// A generated glyph waiting to be spawned as a child entity.
+pub(crate) struct GeneratedGlyph {
+    glyph: GlyphMesh,
+    position: Vec2,
+    /// Rotation along the style's path, if it has one
+    rotation: Option<Quat>,
+    mesh: Mesh,
+}
+
+/// Generate one mesh per visible glyph of a text block.
+///
+/// Glyphs are positioned by their layout; with a path they are anchored like a
+/// [`TextMesh`] and placed along it instead.
+///
+/// Returns `None` if the font data cannot be parsed.
+fn build_glyph_meshes(
+    cache: &GlyphMeshCache,
+    fonts: &[FontSource],
+    text: &str,
+    style: &TextMeshStyle,
+) -> Option<Generated<Vec<GeneratedGlyph>>> {
+    let (ids, faces) = source_faces(fonts)?;
+    let runs = [TextRun {
+        faces: &faces,
+        text,
+        style,
+    }];
+    let layout = layout_runs(&runs, style);
+    let geometry = generate_layout_geometry(cache, &[ids], &runs, &layout.glyphs, style);
+
+    // Anchor the straight text on the path, if any
+    let placement = style.path.as_ref().and_then(PathSampler::new).map(|path| {
+        let (min, max) = geometry.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), (glyph, geometry)| {
+                let (glyph_min, glyph_max) = geometry.bounds();
+                (
+                    min.min(glyph_min + glyph.position),
+                    max.max(glyph_max + glyph.position),
+                )
+            },
+        );
+        let offset = layout_offset(style, Some(&path), min.extend(0.0), max.extend(0.0));
+        (path, offset.truncate())
+    });
+
+    let glyphs = geometry
+        .into_iter()
+        .map(|(glyph, glyph_mesh_data)| {
+            let (position, rotation) = match &placement {
+                Some((path, offset)) => {
+                    let (min, max) = glyph_mesh_data.bounds();
+                    let (position, rotation) =
+                        path.place(glyph.position + *offset, (min.x + max.x) * 0.5);
+                    (position, Some(rotation))
+                }
+                None => (glyph.position, None),
+            };
+            GeneratedGlyph {
+                glyph: GlyphMesh {
+                    char_index: glyph.char_index,
+                    line_index: glyph.line_index,
+                    character: glyph.character,
+                    glyph_id: glyph.glyph_id.0,
+                },
+                position,
+                rotation,
+                mesh: create_mesh_from_data(
+                    glyph_mesh_data.vertices,
+                    glyph_mesh_data.normals,
+                    glyph_mesh_data.uvs,
+                    glyph_mesh_data.indices,
+                    style,
+                ),
+            }
+        })
+        .collect();
+    Some(Generated {
+        meshes: glyphs,
+        missing: layout.missing,
+    })
 }
 
 /// Marker component indicating that a [`TextMesh`] has been processed.
@@ -84,269 +375,823 @@ pub struct TextMeshComputed;
 #[derive(Component)]
 pub struct TextMeshGlyphsComputed;
 
-type TextMeshQuery<'w, 's> = Query<
-    'w,
-    's,
-    (Entity, &'static TextMesh, &'static mut Mesh3d),
-    Or<(Changed<TextMesh>, Without<TextMeshComputed>)>,
->;
+/// Message sent when a text entity contains characters that no font has a glyph for.
+///
+/// Sent every time the entity's meshes are generated, alongside a logged warning.
+/// Whether the characters are drawn is controlled by
+/// [`TextMeshStyle::show_missing_glyphs`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::MissingGlyphs;
+/// fn report_missing_glyphs(mut messages: MessageReader<MissingGlyphs>) {
+///     for message in messages.read() {
+///         let code_points: Vec<String> = message
+///             .characters
+///             .iter()
+///             .map(|ch| format!("U+{:04X}", *ch as u32))
+///             .collect();
+///         error!("{} is missing {}", message.entity, code_points.join(", "));
+///     }
+/// }
+/// ```
+#[derive(Message, Clone, Debug)]
+pub struct MissingGlyphs {
+    /// The text entity ([`TextMesh`], [`TextMeshGlyphs`] or [`TextMeshSpans`])
+    pub entity: Entity,
+    /// The missing characters, without duplicates
+    pub characters: Vec<char>,
+}
 
-pub fn update_text_meshes(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    font_assets: Res<Assets<FontMesh>>,
-    mut query: TextMeshQuery,
+/// Log and send a [`MissingGlyphs`] message if any characters are missing.
+fn report_missing_glyphs(
+    messages: &mut MessageWriter<MissingGlyphs>,
+    entity: Entity,
+    characters: Vec<char>,
 ) {
-    for (entity, text_mesh, mut mesh_handle) in query.iter_mut() {
-        // 1. Try to get the font data
-        let Some(font_asset) = font_assets.get(&text_mesh.font) else {
-            // Font not loaded yet, skip this frame
-            continue;
-        };
-
-        // 2. Parse font directly (no caching needed as parsing is lightweight)
-        let Ok(face) = fontmesh::Face::parse(&font_asset.data, 0) else {
-            // Failed to parse font, skip this entity
-            continue;
-        };
+    if characters.is_empty() {
+        return;
+    }
+    warn!(
+        "Text entity {entity} has no glyph for {}",
+        characters
+            .iter()
+            .map(|ch| format!("{ch:?} (U+{:04X})", *ch as u32))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    messages.write(MissingGlyphs { entity, characters });
+}
 
-        // 3. Generate combined mesh
-        let mut all_vertices = Vec::new();
-        let mut all_normals = Vec::new();
-        let mut all_indices = Vec::new();
+/// Generation work done so far in the current frame, shared by all systems.
+///
+/// With async generation, the time spent applying finished tasks is charged too.
+#[derive(Resource, Default)]
+pub(crate) struct FrameBudget {
+    entities: usize,
+    glyphs: usize,
+    time: Duration,
+    /// Finished tasks applied this frame
+    applied: usize,
+}
 
-        let mut cursor = Vec3::ZERO;
-        let mut index_offset = 0;
+impl FrameBudget {
+    /// Whether a text with `glyphs` glyphs still fits in this frame's budget.
+    fn allows(&self, settings: &FontMeshSettings, glyphs: usize) -> bool {
+        // Always make progress, even if a single text exceeds the budget
+        if self.entities == 0 {
+            return true;
+        }
+        let glyphs_fit = settings
+            .max_glyphs_per_frame
+            .is_none_or(|max| self.glyphs + glyphs <= max);
+        let time_fits = settings
+            .max_time_per_frame
+            .is_none_or(|max| self.time < max);
+        glyphs_fit && time_fits
+    }
 
-        let line_height =
-            fontmesh::ascender(&face) - fontmesh::descender(&face) + fontmesh::line_gap(&face);
+    fn spend(&mut self, glyphs: usize, time: Duration) {
+        self.entities += 1;
+        self.glyphs += glyphs;
+        self.time += time;
+    }
 
-        // Bounds tracking
-        let mut min_bound = Vec3::splat(f32::MAX);
-        let mut max_bound = Vec3::splat(f32::MIN);
+    /// Whether another finished task can be applied in this frame.
+    ///
+    /// Glyphs were already counted when the task was started, so only time counts.
+    fn allows_apply(&self, settings: &FontMeshSettings) -> bool {
+        // Always apply one task per frame, even if generation spent the budget
+        self.applied == 0
+            || settings
+                .max_time_per_frame
+                .is_none_or(|max| self.time < max)
+    }
 
-        // Split text into lines for justification
-        for line in text_mesh.text.split('\n') {
-            // Calculate line width and X offset based on justification
-            let line_width = calculate_line_width(line, &face);
-            cursor.x = calculate_justification_offset(text_mesh.style.justify, line_width);
+    fn spend_apply(&mut self, time: Duration) {
+        self.applied += 1;
+        self.time += time;
+    }
+}
 
-            // Generate mesh for line
-            for ch in line.chars() {
-                if ch.is_whitespace() {
-                    cursor.x += get_glyph_advance(ch, &face);
-                    continue;
-                }
+/// System that resets the [`FrameBudget`] at the start of each frame.
+pub(crate) fn reset_frame_budget(mut budget: ResMut<FrameBudget>) {
+    *budget = FrameBudget::default();
+}
 
-                // Use pure function to generate mesh
-                let mesh_res = fontmesh::char_to_mesh_3d(
-                    &face,
-                    ch,
-                    text_mesh.style.depth,
-                    text_mesh.style.subdivision,
-                );
+/// Estimate the number of glyphs a text generates, for budgeting
+#[inline]
+fn glyph_cost(text: &str) -> usize {
+    text.chars().filter(|ch| !ch.is_whitespace()).count()
+}
 
-                if let Ok(mesh) = mesh_res {
-                    // Extend vertices and update bounds
-                    all_vertices.extend(mesh.vertices.iter().map(|v| {
-                        let pos = Vec3::new(v.x + cursor.x, v.y + cursor.y, v.z);
-                        min_bound = min_bound.min(pos);
-                        max_bound = max_bound.max(pos);
-                        [pos.x, pos.y, pos.z]
-                    }));
+/// Background task generating the meshes of a text entity.
+#[derive(Component)]
+pub(crate) struct GenerationTask<T: Send + Sync + 'static>(Task<Option<Generated<T>>>);
 
-                    // Extend normals
-                    all_normals.extend(mesh.normals.iter().map(|n| [n.x, n.y, n.z]));
+/// Shared state of the systems that generate text meshes and apply the results.
+///
+/// [`TextMesh`], [`TextMeshGlyphs`] and [`TextMeshSpans`] only differ in how their
+/// meshes are built and how the result is applied to the entity.
+#[derive(SystemParam)]
+pub(crate) struct MeshGeneration<'w, 's> {
+    commands: Commands<'w, 's>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    font_assets: Res<'w, Assets<FontMesh>>,
+    cache: Res<'w, GlyphMeshCache>,
+    settings: Res<'w, FontMeshSettings>,
+    budget: ResMut<'w, FrameBudget>,
+    missing_glyphs: MessageWriter<'w, MissingGlyphs>,
+    asset_server: Res<'w, AssetServer>,
+    /// Fallback fonts already reported as unavailable
+    skipped_fallbacks: Local<'s, HashSet<AssetId<FontMesh>>>,
+}
 
-                    // Extend indices with offset
-                    all_indices.extend(mesh.indices.iter().map(|i| i + index_offset));
+impl MeshGeneration<'_, '_> {
+    /// Faces of a font followed by the style's fallback fonts.
+    ///
+    /// Returns `None` until the font and all fallbacks still loading are loaded.
+    /// Fallbacks that failed to load are skipped with a warning.
+    fn font_sources(
+        &mut self,
+        font: &Handle<FontMesh>,
+        style: &TextMeshStyle,
+    ) -> Option<Vec<FontSource>> {
+        let source = |handle: &Handle<FontMesh>, font_asset: &FontMesh| FontSource {
+            font: self.cache.font(handle.id()),
+            face: font_asset.shared_face(),
+        };
+        let mut sources = vec![source(font, self.font_assets.get(font)?)];
 
-                    index_offset += mesh.vertices.len() as u32;
-                    cursor.x += get_glyph_advance(ch, &face);
-                }
+        for fallback in &style.fallback_fonts {
+            if let Some(font_asset) = self.font_assets.get(fallback) {
+                sources.push(source(fallback, font_asset));
+            } else if self.asset_server.load_state(fallback).is_loading() {
+                return None;
+            } else if self.skipped_fallbacks.insert(fallback.id()) {
+                warn!("Skipping fallback font {fallback:?}, which failed to load");
             }
+        }
+        Some(sources)
+    }
 
-            // Move to next line
-            cursor.y -= line_height;
+    /// Generate the meshes of a changed entity, or start generating them.
+    ///
+    /// With async generation, `build` runs in a background task whose result
+    /// [`Self::apply_finished`] applies later; otherwise its result is applied to
+    /// `target` right away. `computed` marks the entity as processed unless the
+    /// frame's budget defers it.
+    fn generate<T: Send + Sync + 'static, C: Component, X>(
+        &mut self,
+        entity: Entity,
+        target: X,
+        computed: C,
+        cost: usize,
+        build: impl FnOnce(&GlyphMeshCache) -> Option<Generated<T>> + Send + 'static,
+        apply: impl FnOnce(&mut Commands, &mut Assets<Mesh>, Entity, X, T),
+    ) {
+        // Defer to a later frame once the budget is spent
+        if !self.budget.allows(&self.settings, cost) {
+            self.commands.entity(entity).remove::<C>();
+            return;
         }
+        let start = Instant::now();
 
-        // 4. Apply Anchor Offset
-        if !all_vertices.is_empty() {
-            let offset = calculate_anchor_offset(text_mesh.style.anchor, min_bound, max_bound);
-            all_vertices.iter_mut().for_each(|v| {
-                v[0] += offset.x;
-                v[1] += offset.y;
-                v[2] += offset.z;
-            });
+        if self.settings.async_generation {
+            // Replacing a pending task cancels it
+            let cache = self.cache.clone();
+            let task = AsyncComputeTaskPool::get().spawn(async move { build(&cache) });
+            self.commands
+                .entity(entity)
+                .insert((GenerationTask(task), computed));
+            self.budget.spend(cost, start.elapsed());
+            return;
+        }
+
+        let Some(generated) = build(&self.cache) else {
+            // Failed to parse a font, skip this entity
+            return;
+        };
+        report_missing_glyphs(&mut self.missing_glyphs, entity, generated.missing);
+        apply(
+            &mut self.commands,
+            &mut self.meshes,
+            entity,
+            target,
+            generated.meshes,
+        );
+        self.budget.spend(cost, start.elapsed());
+
+        // Mark as computed, discarding any result still pending from async generation
+        self.commands
+            .entity(entity)
+            .remove::<GenerationTask<T>>()
+            .insert(computed);
+    }
+
+    /// Apply the results of finished generation tasks.
+    ///
+    /// Tasks beyond the frame's time budget are applied in a later frame.
+    fn apply_finished<'a, T: Send + Sync + 'static, X>(
+        &mut self,
+        tasks: impl IntoIterator<Item = (Entity, Mut<'a, GenerationTask<T>>, X)>,
+        mut apply: impl FnMut(&mut Commands, &mut Assets<Mesh>, Entity, X, T),
+    ) {
+        for (entity, mut task, target) in tasks {
+            if !self.budget.allows_apply(&self.settings) {
+                return;
+            }
+            let start = Instant::now();
+            let Some(result) = check_ready(&mut task.0) else {
+                continue;
+            };
+            if let Some(generated) = result {
+                report_missing_glyphs(&mut self.missing_glyphs, entity, generated.missing);
+                apply(
+                    &mut self.commands,
+                    &mut self.meshes,
+                    entity,
+                    target,
+                    generated.meshes,
+                );
+            }
+            self.commands.entity(entity).remove::<GenerationTask<T>>();
+            self.budget.spend_apply(start.elapsed());
         }
+    }
+}
+
+type TextMeshQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static TextMesh,
+        &'static mut Mesh3d,
+        Option<&'static ViewVisibility>,
+    ),
+    Or<(Changed<TextMesh>, Without<TextMeshComputed>)>,
+>;
 
-        // 5. Create and assign Bevy Mesh
-        let new_mesh = create_mesh_from_data(all_vertices, all_normals, all_indices);
-        mesh_handle.0 = meshes.add(new_mesh);
+/// System to generate meshes for [`TextMesh`] components.
+///
+/// Depending on [`FontMeshSettings::async_generation`], meshes are generated
+/// in the same frame or in background tasks that [`poll_text_mesh_tasks`] applies.
+/// Entities beyond the frame's generation budget are deferred, visible ones first.
+pub fn update_text_meshes(mut generation: MeshGeneration, mut query: TextMeshQuery) {
+    // Process entities visible in the last frame first
+    let mut pending: Vec<_> = query.iter_mut().collect();
+    pending.sort_by_key(|(.., visibility)| !visibility.is_some_and(|v| v.get()));
 
-        // 7. Mark as computed
-        commands.entity(entity).insert(TextMeshComputed);
+    for (entity, text_mesh, mesh_handle, _) in pending {
+        // Try to get the data of the font and its fallbacks
+        let Some(fonts) = generation.font_sources(&text_mesh.font, &text_mesh.style) else {
+            // Fonts not loaded yet, skip this frame
+            continue;
+        };
+        let (text, style) = (text_mesh.text.clone(), text_mesh.style.clone());
+        generation.generate(
+            entity,
+            mesh_handle,
+            TextMeshComputed,
+            glyph_cost(&text_mesh.text),
+            move |cache| build_text_mesh(cache, &fonts, &text, &style),
+            apply_text_mesh,
+        );
     }
 }
 
-type TextMeshGlyphsQuery<'w, 's> = Query<
+/// Replace the mesh of a [`TextMesh`] entity.
+fn apply_text_mesh(
+    _: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    _: Entity,
+    mut mesh_handle: Mut<Mesh3d>,
+    mesh: Mesh,
+) {
+    mesh_handle.0 = meshes.add(mesh);
+}
+
+/// System that applies finished [`TextMesh`] generation tasks.
+///
+/// The previous mesh stays visible until its replacement is ready. Tasks beyond
+/// the frame's time budget are applied in a later frame.
+pub fn poll_text_mesh_tasks(
+    mut generation: MeshGeneration,
+    mut query: Query<(Entity, &mut GenerationTask<Mesh>, &mut Mesh3d)>,
+) {
+    generation.apply_finished(query.iter_mut(), apply_text_mesh);
+}
+
+type TextMeshGlyphsQuery<'w, 's, M> = Query<
     'w,
     's,
     (
         Entity,
         &'static TextMeshGlyphs,
-        &'static MeshMaterial3d<StandardMaterial>,
+        &'static MeshMaterial3d<M>,
+        Option<&'static ViewVisibility>,
     ),
     Or<(Changed<TextMeshGlyphs>, Without<TextMeshGlyphsComputed>)>,
 >;
 
+/// Middle sections larger than this (old × new glyphs) are replaced instead of diffed.
+const MAX_DIFF_CELLS: usize = 1 << 20;
+
+/// Match new glyphs to old glyphs that can be reused for them.
+///
+/// Glyphs are compared by `(glyph_id, character)` in text order. Returns, for every
+/// new glyph, the index of the old glyph it keeps. Matches preserve order, so an
+/// insertion or deletion only affects the glyphs that were actually edited.
+fn match_glyphs(old: &[(u16, char)], new: &[(u16, char)]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; new.len()];
+
+    // Common prefix and suffix cover the usual case of a local edit
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    for (i, matched) in matches.iter_mut().enumerate().take(prefix) {
+        *matched = Some(i);
+    }
+    for (i, matched) in matches.iter_mut().rev().enumerate().take(suffix) {
+        *matched = Some(old.len() - 1 - i);
+    }
+
+    // Longest common subsequence of the edited middle
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+    let (rows, cols) = (old_mid.len(), new_mid.len());
+    if rows == 0 || cols == 0 || rows * cols > MAX_DIFF_CELLS {
+        return matches;
+    }
+
+    let mut lengths = vec![0u32; (rows + 1) * (cols + 1)];
+    let at = |i: usize, j: usize| i * (cols + 1) + j;
+    for i in (0..rows).rev() {
+        for j in (0..cols).rev() {
+            lengths[at(i, j)] = if old_mid[i] == new_mid[j] {
+                lengths[at(i + 1, j + 1)] + 1
+            } else {
+                lengths[at(i + 1, j)].max(lengths[at(i, j + 1)])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < rows && j < cols {
+        if old_mid[i] == new_mid[j] {
+            matches[prefix + j] = Some(prefix + i);
+            i += 1;
+            j += 1;
+        } else if lengths[at(i + 1, j)] >= lengths[at(i, j + 1)] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    matches
+}
+
+/// Rotation last given to a glyph child by its path, to tell it apart from the user's.
+#[derive(Component)]
+pub(crate) struct PathRotation(Quat);
+
+type GlyphChildQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static GlyphMesh,
+        &'static Transform,
+        Option<&'static PathRotation>,
+    ),
+>;
+
+/// Update the glyph children of `entity` to match newly generated glyphs.
+///
+/// Existing children are reused for unchanged glyphs, keeping any components,
+/// materials or rotation and scale added by the user; only their mesh, glyph
+/// info and translation are updated. Text on a path also sets their rotation,
+/// which is reset once the glyph is no longer on a path. Glyphs that were removed
+/// are despawned and inserted glyphs are spawned with the parent's material.
+fn spawn_glyph_children<M: Material>(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    entity: Entity,
+    glyphs: Vec<GeneratedGlyph>,
+    default_material: &MeshMaterial3d<M>,
+    children_query: &Query<&Children>,
+    glyph_query: &GlyphChildQuery,
+) {
+    // Existing glyph children in text order
+    let mut existing: Vec<(Entity, &GlyphMesh, &Transform, Option<&PathRotation>)> = children_query
+        .get(entity)
+        .map(|children| {
+            children
+                .iter()
+                .filter_map(|child| {
+                    let (glyph, transform, path_rotation) = glyph_query.get(child).ok()?;
+                    Some((child, glyph, transform, path_rotation))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    existing.sort_by_key(|(_, glyph, ..)| glyph.char_index);
+
+    // Layout yields glyphs in visual order, which is reversed in right-to-left runs
+    let mut glyphs = glyphs;
+    glyphs.sort_by_key(|generated| generated.glyph.char_index);
+
+    let key = |glyph: &GlyphMesh| (glyph.glyph_id, glyph.character);
+    let old_keys: Vec<_> = existing.iter().map(|(_, glyph, ..)| key(glyph)).collect();
+    let new_keys: Vec<_> = glyphs
+        .iter()
+        .map(|generated| key(&generated.glyph))
+        .collect();
+    let matches = match_glyphs(&old_keys, &new_keys);
+
+    // Despawn glyph children that have no counterpart anymore
+    let mut reused = vec![false; existing.len()];
+    for &old_index in matches.iter().flatten() {
+        reused[old_index] = true;
+    }
+    for ((child, ..), _) in existing.iter().zip(&reused).filter(|(_, &r)| !r) {
+        commands.entity(*child).despawn();
+    }
+
+    let mut spawned = Vec::new();
+    for (generated, old_index) in glyphs.into_iter().zip(matches) {
+        let mesh_handle = meshes.add(generated.mesh);
+        let translation = generated.position.extend(0.0);
+
+        match old_index {
+            Some(old_index) => {
+                // Move the existing glyph entity into place
+                let (child, _, transform, path_rotation) = existing[old_index];
+                let set_by_path = path_rotation.map_or(Quat::IDENTITY, |r| r.0);
+                let rotation = match generated.rotation {
+                    Some(rotation) => rotation,
+                    // Keep a rotation the user set, but undo the path's
+                    None if transform.rotation != set_by_path => transform.rotation,
+                    None => Quat::IDENTITY,
+                };
+                let mut child = commands.entity(child);
+                child.insert((
+                    generated.glyph,
+                    Mesh3d(mesh_handle),
+                    Transform {
+                        translation,
+                        rotation,
+                        ..*transform
+                    },
+                ));
+                match generated.rotation {
+                    Some(rotation) => child.insert(PathRotation(rotation)),
+                    None => child.remove::<PathRotation>(),
+                };
+            }
+            None => spawned.push((
+                (
+                    generated.glyph,
+                    Mesh3d(mesh_handle),
+                    default_material.clone(),
+                    Transform::from_translation(translation)
+                        .with_rotation(generated.rotation.unwrap_or_default()),
+                    Visibility::default(),
+                    InheritedVisibility::default(),
+                    ViewVisibility::default(),
+                ),
+                generated.rotation,
+            )),
+        }
+    }
+
+    // Spawn glyph entities for inserted glyphs
+    commands.entity(entity).with_children(|parent| {
+        for (bundle, rotation) in spawned {
+            let mut child = parent.spawn(bundle);
+            if let Some(rotation) = rotation {
+                child.insert(PathRotation(rotation));
+            }
+        }
+    });
+}
+
 /// System to generate per-character mesh entities for [`TextMeshGlyphs`] components.
 ///
 /// This system spawns a separate child entity for each character in the text,
-/// allowing for per-character styling, animations, and interactions.
-pub fn update_glyph_meshes(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    font_assets: Res<Assets<FontMesh>>,
-    query: TextMeshGlyphsQuery,
+/// allowing for per-character styling, animations, and interactions. With
+/// [`FontMeshSettings::async_generation`], glyphs are generated in background
+/// tasks and the old children are replaced by [`poll_glyph_mesh_tasks`].
+/// Entities beyond the frame's generation budget are deferred, visible ones first.
+///
+/// Only entities whose material is `MeshMaterial3d<M>` are handled; new glyphs
+/// copy the parent's material. See [`FontMeshGlyphsPlugin`](crate::FontMeshGlyphsPlugin).
+pub fn update_glyph_meshes<M: Material>(
+    mut generation: MeshGeneration,
+    query: TextMeshGlyphsQuery<M>,
     children_query: Query<&Children>,
-    glyph_query: Query<Entity, With<GlyphMesh>>,
+    glyph_query: GlyphChildQuery,
 ) {
-    for (entity, text_glyphs, default_material) in query.iter() {
-        // 1. Try to get the font data
-        let Some(font_asset) = font_assets.get(&text_glyphs.font) else {
-            // Font not loaded yet, skip this frame
+    // Process entities visible in the last frame first
+    let mut pending: Vec<_> = query.iter().collect();
+    pending.sort_by_key(|(.., visibility)| !visibility.is_some_and(|v| v.get()));
+
+    for (entity, text_glyphs, default_material, _) in pending {
+        // Try to get the data of the font and its fallbacks
+        let Some(fonts) = generation.font_sources(&text_glyphs.font, &text_glyphs.style) else {
+            // Fonts not loaded yet, skip this frame
             continue;
         };
+        let (text, style) = (text_glyphs.text.clone(), text_glyphs.style.clone());
+        generation.generate(
+            entity,
+            default_material,
+            TextMeshGlyphsComputed,
+            glyph_cost(&text_glyphs.text),
+            move |cache| build_glyph_meshes(cache, &fonts, &text, &style),
+            |commands, meshes, entity, default_material, glyphs| {
+                spawn_glyph_children(
+                    commands,
+                    meshes,
+                    entity,
+                    glyphs,
+                    default_material,
+                    &children_query,
+                    &glyph_query,
+                );
+            },
+        );
+    }
+}
 
-        // 2. Parse font directly (no caching needed as parsing is lightweight)
-        let Ok(face) = fontmesh::Face::parse(&font_asset.data, 0) else {
-            // Failed to parse font, skip this entity
-            continue;
+type GlyphTaskQuery<'w, 's, M> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut GenerationTask<Vec<GeneratedGlyph>>,
+        &'static MeshMaterial3d<M>,
+    ),
+>;
+
+/// System that applies finished [`TextMeshGlyphs`] generation tasks.
+///
+/// The previous glyph entities stay in place until their replacements are ready.
+/// Tasks beyond the frame's time budget are applied in a later frame.
+pub fn poll_glyph_mesh_tasks<M: Material>(
+    mut generation: MeshGeneration,
+    mut query: GlyphTaskQuery<M>,
+    children_query: Query<&Children>,
+    glyph_query: GlyphChildQuery,
+) {
+    generation.apply_finished(
+        query.iter_mut(),
+        |commands, meshes, entity, default_material, glyphs| {
+            spawn_glyph_children(
+                commands,
+                meshes,
+                entity,
+                glyphs,
+                default_material,
+                &children_query,
+                &glyph_query,
+            );
+        },
+    );
+}
+
+/// Marker component indicating that a [`TextMeshSpans`] has been processed.
+#[derive(Component)]
+pub struct TextMeshSpansComputed;
+
+type TextMeshSpansQuery<'w, 's, M> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static TextMeshSpans,
+        &'static MeshMaterial3d<M>,
+        Option<&'static ViewVisibility>,
+    ),
+    Or<(Changed<TextMeshSpans>, Without<TextMeshSpansComputed>)>,
+>;
+
+type SpanChildQuery<'w, 's> = Query<'w, 's, &'static SpanMesh>;
+
+/// Update the span children of `entity` to match newly generated span meshes.
+///
+/// Existing span entities are reused by span index, keeping their materials and
+/// components; only their mesh and tag are updated.
+#[allow(clippy::too_many_arguments)]
+fn spawn_span_children<M: Material>(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    entity: Entity,
+    span_meshes: Vec<Mesh>,
+    text_spans: &TextMeshSpans,
+    default_material: &MeshMaterial3d<M>,
+    children_query: &Query<&Children>,
+    span_query: &SpanChildQuery,
+) {
+    let mut existing: Vec<Option<Entity>> = vec![None; span_meshes.len()];
+    if let Ok(children) = children_query.get(entity) {
+        for child in children.iter() {
+            let Ok(span) = span_query.get(child) else {
+                continue;
+            };
+            match existing.get_mut(span.span_index) {
+                Some(slot @ None) => *slot = Some(child),
+                // Span was removed (or is a duplicate)
+                _ => commands.entity(child).despawn(),
+            }
+        }
+    }
+
+    for (span_index, (mesh, child)) in span_meshes.into_iter().zip(existing).enumerate() {
+        let span = SpanMesh {
+            span_index,
+            tag: text_spans.spans.get(span_index).map_or(0, |span| span.tag),
         };
+        let mesh_handle = meshes.add(mesh);
 
-        // 3. Despawn existing glyph children
-        if let Ok(children) = children_query.get(entity) {
-            for child in children.iter() {
-                if glyph_query.contains(child) {
-                    commands.entity(child).despawn();
-                }
+        match child {
+            Some(child) => {
+                commands.entity(child).insert((span, Mesh3d(mesh_handle)));
+            }
+            None => {
+                commands.entity(entity).with_child((
+                    span,
+                    Mesh3d(mesh_handle),
+                    default_material.clone(),
+                    Transform::default(),
+                    Visibility::default(),
+                ));
             }
         }
+    }
+}
 
-        // 4. Calculate line widths for justification
-        let line_height =
-            fontmesh::ascender(&face) - fontmesh::descender(&face) + fontmesh::line_gap(&face);
-        let lines: Vec<&str> = text_glyphs.text.split('\n').collect();
+/// System to generate per-span mesh entities for [`TextMeshSpans`] components.
+///
+/// Spans are laid out together and each span becomes one child entity with a
+/// [`SpanMesh`] component. Generation follows the same [`FontMeshSettings`] as
+/// [`update_glyph_meshes`], and all span fonts must be loaded before generation starts.
+pub fn update_span_meshes<M: Material>(
+    mut generation: MeshGeneration,
+    query: TextMeshSpansQuery<M>,
+    children_query: Query<&Children>,
+    span_query: SpanChildQuery,
+) {
+    // Process entities visible in the last frame first
+    let mut pending: Vec<_> = query.iter().collect();
+    pending.sort_by_key(|(.., visibility)| !visibility.is_some_and(|v| v.get()));
 
-        let line_widths: Vec<f32> = lines
+    for (entity, text_spans, default_material, _) in pending {
+        // Try to get the data of every span's font and fallbacks
+        let Some(sources) = text_spans
+            .spans
             .iter()
-            .map(|line| calculate_line_width(line, &face))
-            .collect();
-
-        // 5. Spawn glyph entities
-        let mut char_index = 0;
-
-        commands.entity(entity).with_children(|parent| {
-            for (line_index, line) in lines.iter().enumerate() {
-                let line_width = line_widths[line_index];
-                let mut cursor_x =
-                    calculate_justification_offset(text_glyphs.style.justify, line_width);
-                let cursor_y = -(line_index as f32) * line_height;
-
-                for ch in line.chars() {
-                    let advance = get_glyph_advance(ch, &face);
-
-                    // Skip whitespace but still count it
-                    if ch.is_whitespace() {
-                        cursor_x += advance;
-                        char_index += 1;
-                        continue;
-                    }
-
-                    // Generate mesh for this character using pure function
-                    let mesh_res = fontmesh::char_to_mesh_3d(
-                        &face,
-                        ch,
-                        text_glyphs.style.depth,
-                        text_glyphs.style.subdivision,
-                    );
-
-                    if let Ok(glyph_mesh_data) = mesh_res {
-                        let vertices: Vec<_> = glyph_mesh_data
-                            .vertices
-                            .iter()
-                            .map(|v| [v.x, v.y, v.z])
-                            .collect();
-
-                        let normals: Vec<_> = glyph_mesh_data
-                            .normals
-                            .iter()
-                            .map(|n| [n.x, n.y, n.z])
-                            .collect();
-
-                        let mesh = create_mesh_from_data(
-                            vertices,
-                            normals,
-                            glyph_mesh_data.indices.clone(),
-                        );
-                        let mesh_handle = meshes.add(mesh);
-
-                        // Spawn glyph entity as child
-                        parent.spawn((
-                            GlyphMesh {
-                                char_index,
-                                line_index,
-                                character: ch,
-                            },
-                            Mesh3d(mesh_handle),
-                            default_material.clone(),
-                            Transform::from_xyz(cursor_x, cursor_y, 0.0),
-                            Visibility::default(),
-                            InheritedVisibility::default(),
-                            ViewVisibility::default(),
-                        ));
-                    }
-
-                    cursor_x += advance;
-                    char_index += 1;
-                }
+            .map(|span| {
+                let style = span.resolve_style(&text_spans.style);
+                Some(SpanSource {
+                    fonts: generation.font_sources(&span.font, &style)?,
+                    text: span.text.clone(),
+                    style,
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            // Fonts not loaded yet, skip this frame
+            continue;
+        };
+        let style = text_spans.style.clone();
+        generation.generate(
+            entity,
+            (text_spans, default_material),
+            TextMeshSpansComputed,
+            text_spans.spans.iter().map(|s| glyph_cost(&s.text)).sum(),
+            move |cache| build_span_meshes(cache, &sources, &style),
+            |commands, meshes, entity, (text_spans, default_material), span_meshes| {
+                spawn_span_children(
+                    commands,
+                    meshes,
+                    entity,
+                    span_meshes,
+                    text_spans,
+                    default_material,
+                    &children_query,
+                    &span_query,
+                );
+            },
+        );
+    }
+}
 
-                // Account for newline character in char_index
-                char_index += 1;
-            }
-        });
+type SpanTaskQuery<'w, 's, M> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut GenerationTask<Vec<Mesh>>,
+        &'static TextMeshSpans,
+        &'static MeshMaterial3d<M>,
+    ),
+>;
 
-        // 6. Mark as computed
-        commands.entity(entity).insert(TextMeshGlyphsComputed);
-    }
+/// System that applies finished [`TextMeshSpans`] generation tasks.
+///
+/// The previous span meshes stay in place until their replacements are ready.
+/// Tasks beyond the frame's time budget are applied in a later frame.
+pub fn poll_span_mesh_tasks<M: Material>(
+    mut generation: MeshGeneration,
+    mut query: SpanTaskQuery<M>,
+    children_query: Query<&Children>,
+    span_query: SpanChildQuery,
+) {
+    let tasks = query
+        .iter_mut()
+        .map(|(entity, task, text_spans, default_material)| {
+            (entity, task, (text_spans, default_material))
+        });
+    generation.apply_finished(
+        tasks,
+        |commands, meshes, entity, (text_spans, default_material), span_meshes| {
+            spawn_span_children(
+                commands,
+                meshes,
+                entity,
+                span_meshes,
+                text_spans,
+                default_material,
+                &children_query,
+                &span_query,
+            );
+        },
+    );
 }
 
 /// Helper function to generate a mesh for a single character.
 ///
 /// This can be used to create individual glyph meshes outside of the system,
 /// for example when you need to update a specific character's material.
+/// The character is mapped to a glyph through the font's `cmap` without shaping;
+/// use [`generate_glyph_id_mesh`] for glyphs produced by shaping.
 pub fn generate_glyph_mesh(
     face: &fontmesh::Face,
     character: char,
     depth: f32,
     subdivision: u8,
 ) -> Option<Mesh> {
-    let mesh_res = fontmesh::char_to_mesh_3d(face, character, depth, subdivision);
+    let style = TextMeshStyle {
+        depth,
+        subdivision,
+        ..default()
+    };
+    generate_glyph_mesh_with_style(face, character, &style)
+}
 
-    mesh_res.ok().map(|glyph_mesh_data| {
-        let vertices: Vec<_> = glyph_mesh_data
-            .vertices
-            .iter()
-            .map(|v| [v.x, v.y, v.z])
-            .collect();
+/// Helper function to generate a mesh for a single character using a full style.
+///
+/// Unlike [`generate_glyph_mesh`], this honours every geometry option of the
+/// style, such as [`TextMeshStyle::bevel`]. Layout options are ignored.
+pub fn generate_glyph_mesh_with_style(
+    face: &fontmesh::Face,
+    character: char,
+    style: &TextMeshStyle,
+) -> Option<Mesh> {
+    let glyph_id = face.glyph_index(character)?;
+    generate_glyph_id_mesh(face, glyph_id.0, style)
+}
 
-        let normals: Vec<_> = glyph_mesh_data
-            .normals
-            .iter()
-            .map(|n| [n.x, n.y, n.z])
-            .collect();
+/// Helper function to generate a mesh for a glyph by its ID in the font.
+///
+/// Glyph IDs are available on spawned [`GlyphMesh`] entities, which allows
+/// regenerating ligatures and contextual forms that have no single character.
+pub fn generate_glyph_id_mesh(
+    face: &fontmesh::Face,
+    glyph_id: u16,
+    style: &TextMeshStyle,
+) -> Option<Mesh> {
+    glyph_geometry(face, ttf_parser::GlyphId(glyph_id), style).map(|mut glyph_mesh_data| {
+        // A lone glyph is its own text block
+        if style.uv_mapping != TextUvMapping::Unwrap {
+            let (min, max) = glyph_mesh_data.bounds();
+            glyph_mesh_data.project_uvs(min, max, Vec2::ZERO);
+        }
 
-        create_mesh_from_data(vertices, normals, glyph_mesh_data.indices)
+        create_mesh_from_data(
+            glyph_mesh_data.vertices,
+            glyph_mesh_data.normals,
+            glyph_mesh_data.uvs,
+            glyph_mesh_data.indices,
+            style,
+        )
     })
 }
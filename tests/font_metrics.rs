@@ -1,12 +1,12 @@
 //! Tests for font metrics API
 
-use bevy_fontmesh::FontMesh;
+use bevy_fontmesh::{FontMesh, TextAnchor, TextMeshStyle};
 use std::fs;
 
 fn load_test_font() -> FontMesh {
     let font_bytes = fs::read("assets/fonts/FiraMono-Medium.ttf")
         .expect("Failed to load test font - make sure assets/fonts/FiraMono-Medium.ttf exists");
-    FontMesh { data: font_bytes }
+    FontMesh::new(font_bytes)
 }
 
 #[test]
@@ -97,3 +97,162 @@ fn test_empty_text_width() {
         "Empty text should have no positions"
     );
 }
+
+#[test]
+fn test_kerning_consistency() {
+    let font = load_test_font();
+    let text = "AVATAR To Wa";
+
+    // Without kerning, width is exactly the sum of advances
+    let plain = TextMeshStyle {
+        kerning: false,
+        ..Default::default()
+    };
+    let manual_width: f32 = text
+        .chars()
+        .filter_map(|ch| font.glyph_metrics(ch))
+        .map(|m| m.advance)
+        .sum();
+    assert!(
+        (font.text_width_with_style(text, &plain) - manual_width).abs() < 0.001,
+        "Unkerned width should equal sum of advances"
+    );
+
+    // The fixture kerns "AV" and "To", so kerning must tighten the text
+    let kerned = TextMeshStyle::default();
+    for pair in ["AV", "To"] {
+        assert!(
+            font.text_width_with_style(pair, &kerned)
+                < font.text_width_with_style(pair, &plain) - 0.01,
+            "Kerning should tighten {pair:?}"
+        );
+    }
+    assert!(font.text_width_with_style(text, &kerned) < manual_width);
+
+    // With kerning, width and positions must agree with each other
+    let positions = font.char_positions_with_style(text, &kerned);
+    let last_advance = font.glyph_metrics('a').unwrap().advance;
+    assert!(
+        (font.text_width_with_style(text, &kerned) - (positions.last().unwrap().1 + last_advance))
+            .abs()
+            < 0.001,
+        "Kerned width should end at the last character's advance"
+    );
+}
+
+#[test]
+fn test_letter_and_word_spacing() {
+    let font = load_test_font();
+    let plain = TextMeshStyle {
+        kerning: false,
+        ..Default::default()
+    };
+    let spaced = TextMeshStyle {
+        letter_spacing: 0.1,
+        word_spacing: 0.5,
+        ..plain.clone()
+    };
+
+    // Letter spacing goes between characters, word spacing after every space
+    let text = "ab cd";
+    let expected = font.text_width_with_style(text, &plain) + 4.0 * 0.1 + 0.5;
+    assert!((font.text_width_with_style(text, &spaced) - expected).abs() < 0.001);
+
+    let plain_positions = font.char_positions_with_style(text, &plain);
+    let spaced_positions = font.char_positions_with_style(text, &spaced);
+    let shift = spaced_positions[3].1 - plain_positions[3].1;
+    assert!(
+        (shift - (3.0 * 0.1 + 0.5)).abs() < 0.001,
+        "'c' should move by three letter spacings and one word spacing"
+    );
+
+    // Negative tracking tightens text
+    let tight = TextMeshStyle {
+        letter_spacing: -0.05,
+        ..plain.clone()
+    };
+    assert!(font.text_width_with_style(text, &tight) < font.text_width_with_style(text, &plain));
+}
+
+#[test]
+fn test_glyph_and_text_contours() {
+    let font = load_test_font();
+
+    // 'O' has an outer contour and a hole, in em units
+    let contours = font
+        .glyph_contours('O', 8)
+        .expect("'O' should have contours");
+    assert_eq!(contours.len(), 2);
+    assert_eq!(contours.iter().filter(|c| c.is_hole).count(), 1);
+    for contour in &contours {
+        assert!(contour
+            .points
+            .iter()
+            .all(|p| p.x.abs() < 1.0 && p.y.abs() < 1.0));
+    }
+    assert!(
+        font.glyph_contours(' ', 8).is_none(),
+        "Space has no outline"
+    );
+    assert!(font.glyph_contours('日', 8).is_none(), "Missing character");
+
+    // Text contours are laid out, scaled and anchored like a TextMesh
+    let style = TextMeshStyle {
+        font_size: 2.0,
+        anchor: TextAnchor::BottomLeft,
+        ..Default::default()
+    };
+    let contours = font.text_contours("Oi", &style);
+    assert!(contours.iter().any(|(index, _)| *index == 0));
+    assert!(contours.iter().any(|(index, _)| *index == 1));
+
+    let points = || contours.iter().flat_map(|(_, contour)| &contour.points);
+    let min_x = points().map(|p| p.x).fold(f32::MAX, f32::min);
+    let min_y = points().map(|p| p.y).fold(f32::MAX, f32::min);
+    assert!(min_x.abs() < 1e-5 && min_y.abs() < 1e-5);
+
+    let max_x = |char_index: usize| {
+        contours
+            .iter()
+            .filter(|(index, _)| *index == char_index)
+            .flat_map(|(_, contour)| &contour.points)
+            .map(|p| p.x)
+            .fold(f32::MIN, f32::max)
+    };
+    assert!(max_x(1) > max_x(0), "'i' should follow 'O'");
+    assert!(max_x(0) > 1.0, "Contours should be scaled by font size");
+}
+
+#[test]
+fn test_invalid_font_data() {
+    let font = FontMesh::new(b"not a font".to_vec());
+
+    assert!(font.font_metrics().is_none());
+    assert!(font.glyph_metrics('A').is_none());
+    assert_eq!(font.text_width("Hello"), 0.0);
+    assert!(font.char_positions("Hello").is_empty());
+    assert!(font.glyph_contours('A', 8).is_none());
+    assert!(font
+        .text_contours("Hello", &TextMeshStyle::default())
+        .is_empty());
+    assert_eq!(font.data(), b"not a font");
+}
+
+#[test]
+fn test_cmap_entries_past_glyph_count_are_ignored() {
+    let mut data = fs::read("assets/fonts/FiraMono-Medium.ttf").unwrap();
+
+    // Shrink the glyph count in `maxp` so most cmap entries point past it
+    let num_tables = u16::from_be_bytes([data[4], data[5]]) as usize;
+    let maxp = (0..num_tables)
+        .map(|i| 12 + 16 * i)
+        .find(|&record| &data[record..record + 4] == b"maxp")
+        .map(|record| u32::from_be_bytes(data[record + 8..record + 12].try_into().unwrap()))
+        .expect("Test font should have a maxp table") as usize;
+    data[maxp + 4..maxp + 6].copy_from_slice(&4u16.to_be_bytes());
+
+    let font = FontMesh::new(data);
+    assert!(font.font_metrics().is_some());
+    assert!(font.glyph_metrics('A').is_none());
+    assert!(font.glyph_contours('A', 8).is_none());
+}
@@ -0,0 +1,1387 @@
+//! Tests for mesh generation through the plugin systems
+
+use bevy::camera::visibility::SetViewVisibility;
+use bevy::mesh::{Indices, VertexAttributeValues};
+use bevy::prelude::*;
+use bevy::render::render_resource::AsBindGroup;
+use bevy_fontmesh::prelude::*;
+use std::fs;
+use std::time::Duration;
+
+fn load_test_font() -> FontMesh {
+    let font_bytes = fs::read("assets/fonts/FiraMono-Medium.ttf")
+        .expect("Failed to load test font - make sure assets/fonts/FiraMono-Medium.ttf exists");
+    FontMesh::new(font_bytes)
+}
+
+fn test_app() -> (App, Handle<FontMesh>) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .add_plugins(FontMeshPlugin)
+        .insert_resource(FontMeshSettings {
+            async_generation: false,
+            ..default()
+        });
+
+    let font = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(load_test_font());
+    (app, font)
+}
+
+fn positions(mesh: &Mesh) -> &[[f32; 3]] {
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(values)) => values,
+        _ => panic!("Mesh should have positions"),
+    }
+}
+
+fn normals(mesh: &Mesh) -> &[[f32; 3]] {
+    match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        Some(VertexAttributeValues::Float32x3(values)) => values,
+        _ => panic!("Mesh should have normals"),
+    }
+}
+
+fn indices(mesh: &Mesh) -> Vec<usize> {
+    match mesh.indices() {
+        Some(Indices::U32(values)) => values.iter().map(|&i| i as usize).collect(),
+        _ => panic!("Mesh should have u32 indices"),
+    }
+}
+
+fn assert_winding_matches_normals(mesh: &Mesh) {
+    let positions = positions(mesh);
+    let normals = normals(mesh);
+
+    for tri in indices(mesh).chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[tri[i]]));
+        let face_normal = (b - a).cross(c - a);
+        let vertex_normal = Vec3::from(normals[tri[0]]);
+        assert!(
+            face_normal.dot(vertex_normal) >= -1e-6,
+            "Triangle winding should agree with its normal"
+        );
+    }
+}
+
+#[test]
+fn test_glyph_mesh_winding_matches_normals() {
+    let font = load_test_font();
+    let face = fontmesh::parse_font(font.data()).unwrap();
+    let mesh = generate_glyph_mesh(&face, 'B', 0.2, 10).expect("'B' should have a mesh");
+
+    let positions = positions(&mesh);
+    let normals = normals(&mesh);
+    assert_eq!(positions.len(), normals.len());
+
+    assert_winding_matches_normals(&mesh);
+
+    // Side walls on the right edge should face outward
+    let (_, rightmost_normal) = positions
+        .iter()
+        .zip(normals)
+        .filter(|(_, n)| n[2] == 0.0)
+        .max_by(|(a, _), (b, _)| a[0].total_cmp(&b[0]))
+        .unwrap();
+    assert!(rightmost_normal[0] > 0.0, "Side walls should face outward");
+
+    // Front face at z = 0, back face at z = -depth
+    assert!(positions.iter().all(|p| p[2] <= 0.0 && p[2] >= -0.2));
+    assert!(generate_glyph_mesh(&face, ' ', 0.2, 10).is_none());
+}
+
+#[test]
+fn test_bevelled_glyph_mesh() {
+    let font = load_test_font();
+    let face = fontmesh::parse_font(font.data()).unwrap();
+    let plain = generate_glyph_mesh(&face, 'O', 0.2, 10).unwrap();
+
+    for segments in [1, 4] {
+        let style = TextMeshStyle {
+            depth: 0.2,
+            subdivision: 10,
+            bevel: Some(TextBevel {
+                width: 0.01,
+                depth: 0.02,
+                segments,
+            }),
+            ..default()
+        };
+        let mesh = generate_glyph_mesh_with_style(&face, 'O', &style).unwrap();
+        assert_winding_matches_normals(&mesh);
+        assert!(mesh.count_vertices() > plain.count_vertices());
+
+        // The bevel insets the front face but keeps the outer silhouette
+        let front_max_x = positions(&mesh)
+            .iter()
+            .zip(normals(&mesh))
+            .filter(|(_, n)| n[2] == 1.0)
+            .map(|(p, _)| p[0])
+            .fold(f32::MIN, f32::max);
+        let max_x = positions(&mesh)
+            .iter()
+            .map(|p| p[0])
+            .fold(f32::MIN, f32::max);
+        let plain_max_x = positions(&plain)
+            .iter()
+            .map(|p| p[0])
+            .fold(f32::MIN, f32::max);
+        assert!(front_max_x < max_x - 0.005);
+        assert!((max_x - plain_max_x).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_stroked_glyph_mesh() {
+    let font = load_test_font();
+    let face = fontmesh::parse_font(font.data()).unwrap();
+    let front_area = |mesh: &Mesh| {
+        let positions = positions(mesh);
+        indices(mesh)
+            .chunks_exact(3)
+            .filter(|tri| normals(mesh)[tri[0]][2] == 1.0)
+            .map(|tri| {
+                let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[tri[i]]));
+                (b - a).cross(c - a).z * 0.5
+            })
+            .sum::<f32>()
+    };
+    let max_x = |mesh: &Mesh| {
+        positions(mesh)
+            .iter()
+            .map(|p| p[0])
+            .fold(f32::MIN, f32::max)
+    };
+
+    let solid = generate_glyph_mesh_with_style(&face, 'H', &TextMeshStyle::default()).unwrap();
+    let style = TextMeshStyle {
+        fill: TextFill::Stroke { width: 0.02 },
+        ..default()
+    };
+    let stroked = generate_glyph_mesh_with_style(&face, 'H', &style).unwrap();
+    assert_winding_matches_normals(&stroked);
+
+    // The band reaches half its width outside the outline and leaves the inside open
+    assert!((max_x(&stroked) - max_x(&solid) - 0.01).abs() < 1e-4);
+    assert!(front_area(&stroked) < front_area(&solid) * 0.5);
+    assert!(front_area(&stroked) > 0.0);
+}
+
+#[test]
+fn test_flat_glyph_mesh() {
+    let font = load_test_font();
+    let face = fontmesh::parse_font(font.data()).unwrap();
+    let extruded = generate_glyph_mesh_with_style(&face, 'O', &TextMeshStyle::default()).unwrap();
+
+    let flat_style = TextMeshStyle {
+        extrusion: TextExtrusion::Flat,
+        ..default()
+    };
+    let flat = generate_glyph_mesh_with_style(&face, 'O', &flat_style).unwrap();
+    assert!(positions(&flat).iter().all(|p| p[2] == 0.0));
+    assert!(normals(&flat).iter().all(|n| *n == [0.0, 0.0, 1.0]));
+    assert!(flat.count_vertices() * 2 < extruded.count_vertices());
+    assert_winding_matches_normals(&flat);
+
+    let double_sided = generate_glyph_mesh_with_style(
+        &face,
+        'O',
+        &TextMeshStyle {
+            extrusion: TextExtrusion::FlatDoubleSided,
+            ..flat_style
+        },
+    )
+    .unwrap();
+    assert_eq!(double_sided.count_vertices(), flat.count_vertices() * 2);
+    assert_eq!(
+        normals(&double_sided)
+            .iter()
+            .filter(|n| **n == [0.0, 0.0, -1.0])
+            .count(),
+        flat.count_vertices()
+    );
+    assert_winding_matches_normals(&double_sided);
+}
+
+/// Spawn per-glyph text and return its glyphs sorted by char index
+fn spawn_glyphs(text: &str, style: TextMeshStyle) -> Vec<(GlyphMesh, Transform)> {
+    let (mut app, font) = test_app();
+    let parent = app
+        .world_mut()
+        .spawn(TextMeshGlyphsBundle {
+            text_glyphs: TextMeshGlyphs {
+                text: text.to_string(),
+                font,
+                style,
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let mut glyphs: Vec<(GlyphMesh, Transform)> = app
+        .world_mut()
+        .query::<(&GlyphMesh, &Transform, &ChildOf)>()
+        .iter(app.world())
+        .filter(|(_, _, child_of)| child_of.parent() == parent)
+        .map(|(glyph, transform, _)| (glyph.clone(), *transform))
+        .collect();
+    glyphs.sort_by_key(|(g, _)| g.char_index);
+    glyphs
+}
+
+#[test]
+fn test_glyph_entities_map_to_char_indices() {
+    let glyphs = spawn_glyphs("a b\ncd", TextMeshStyle::default());
+
+    let summary: Vec<(usize, usize, char)> = glyphs
+        .iter()
+        .map(|(g, _)| (g.char_index, g.line_index, g.character))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![(0, 0, 'a'), (2, 0, 'b'), (4, 1, 'c'), (5, 1, 'd')],
+        "Whitespace and newlines should be skipped but still counted"
+    );
+}
+
+#[test]
+fn test_word_wrap() {
+    let font = load_test_font();
+    let advance = font.glyph_metrics('a').unwrap().advance;
+
+    // Room for "aaa bbb" but not "aaa bbb ccc"
+    let style = TextMeshStyle {
+        max_width: Some(advance * 8.5),
+        ..default()
+    };
+    let glyphs = spawn_glyphs("aaa bbb ccc", style.clone());
+
+    let lines: Vec<usize> = glyphs.iter().map(|(g, _)| g.line_index).collect();
+    assert_eq!(lines, vec![0, 0, 0, 0, 0, 0, 1, 1, 1]);
+
+    // Wrapped line starts at the left edge again
+    let (first, wrapped) = (glyphs[0].1.translation, glyphs[6].1.translation);
+    assert!((wrapped.x - first.x).abs() < 1e-5);
+    assert!(wrapped.y < first.y);
+
+    // Character wrapping breaks inside words
+    let glyphs = spawn_glyphs(
+        "aaaaaa",
+        TextMeshStyle {
+            wrap: TextWrap::Character,
+            max_width: Some(advance * 4.5),
+            ..default()
+        },
+    );
+    let lines: Vec<usize> = glyphs.iter().map(|(g, _)| g.line_index).collect();
+    assert_eq!(lines, vec![0, 0, 0, 0, 1, 1]);
+}
+
+#[test]
+fn test_justified_lines_fill_max_width() {
+    let font = load_test_font();
+    let max_width = font.text_width("aa bb cc dd") - 0.1;
+
+    // Wraps into "aa bb cc" / "dd"
+    let glyphs = spawn_glyphs(
+        "aa bb cc dd",
+        TextMeshStyle {
+            justify: JustifyText::Justified,
+            max_width: Some(max_width),
+            ..default()
+        },
+    );
+    let x = |index: usize| glyphs[index].1.translation.x;
+    let extra = max_width - font.text_width("aa bb cc");
+
+    // Last glyph of the first line ends exactly at max_width
+    let c_advance = font.glyph_metrics('c').unwrap().advance;
+    assert!((x(5) + c_advance - max_width).abs() < 1e-4);
+    // Both gaps are widened equally
+    assert!((x(2) - font.char_positions("aa bb")[3].1 - extra * 0.5).abs() < 1e-4);
+    assert!((x(4) - font.char_positions("aa bb cc")[6].1 - extra).abs() < 1e-4);
+    // Last line stays left-aligned with natural spacing
+    assert!((x(6) - x(0)).abs() < 1e-5);
+    assert!((x(7) - x(6) - font.glyph_metrics('d').unwrap().advance).abs() < 1e-5);
+}
+
+#[test]
+fn test_justified_text_without_wrapping_is_start_aligned() {
+    let text = "aa bb cc\ndd";
+    let start = spawn_glyphs(text, TextMeshStyle::default());
+    for style in [
+        TextMeshStyle {
+            justify: JustifyText::Justified,
+            ..default()
+        },
+        TextMeshStyle {
+            justify: JustifyText::Justified,
+            wrap: TextWrap::NoWrap,
+            max_width: Some(100.0),
+            ..default()
+        },
+    ] {
+        let justified = spawn_glyphs(text, style);
+        for ((_, a), (_, b)) in start.iter().zip(&justified) {
+            assert!((a.translation - b.translation).length() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_letter_spacing_is_not_added_after_last_character() {
+    let font = load_test_font();
+    let spaced = TextMeshStyle {
+        letter_spacing: 0.1,
+        ..default()
+    };
+
+    // "ab cd" is four letter spacings wider than without spacing, so it fits
+    let max_width = font.text_width("ab cd") + 4.0 * 0.1 + 1e-3;
+    let glyphs = spawn_glyphs(
+        "ab cd",
+        TextMeshStyle {
+            max_width: Some(max_width),
+            ..spaced.clone()
+        },
+    );
+    assert!(glyphs.iter().all(|(g, _)| g.line_index == 0));
+
+    // Justified lines end exactly at max_width, without a spacing after them
+    let max_width = font.text_width_with_style("aa bb cc dd", &spaced) - 0.1;
+    let glyphs = spawn_glyphs(
+        "aa bb cc dd",
+        TextMeshStyle {
+            justify: JustifyText::Justified,
+            max_width: Some(max_width),
+            ..spaced
+        },
+    );
+    let c_advance = font.glyph_metrics('c').unwrap().advance;
+    assert!((glyphs[5].1.translation.x + c_advance - max_width).abs() < 1e-4);
+}
+
+#[test]
+fn test_text_mesh_is_generated() {
+    let (mut app, font) = test_app();
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Hi".to_string(),
+                font,
+                style: TextMeshStyle {
+                    anchor: TextAnchor::BottomLeft,
+                    ..default()
+                },
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let handle = app.world().get::<Mesh3d>(entity).unwrap().0.clone();
+    let meshes = app.world().resource::<Assets<Mesh>>();
+    let mesh = meshes.get(&handle).expect("Mesh should be generated");
+
+    let positions = positions(mesh);
+    assert!(!positions.is_empty());
+    let min_x = positions.iter().map(|p| p[0]).fold(f32::MAX, f32::min);
+    let min_y = positions.iter().map(|p| p[1]).fold(f32::MAX, f32::min);
+    assert!(
+        min_x.abs() < 1e-5 && min_y.abs() < 1e-5,
+        "Anchor should move bounds to origin"
+    );
+}
+
+#[test]
+fn test_uv_mapping() {
+    let font = load_test_font();
+    let face = fontmesh::parse_font(font.data()).unwrap();
+
+    for uv_mapping in [TextUvMapping::Glyph, TextUvMapping::Unwrap] {
+        let style = TextMeshStyle {
+            uv_mapping,
+            ..default()
+        };
+        let mesh = generate_glyph_mesh_with_style(&face, 'B', &style).unwrap();
+        let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(VertexAttributeValues::Float32x2(values)) => values,
+            _ => panic!("Mesh should have UVs"),
+        };
+        assert_eq!(uvs.len(), mesh.count_vertices());
+
+        if uv_mapping == TextUvMapping::Glyph {
+            assert!(uvs
+                .iter()
+                .all(|uv| (-1e-5..=1.0 + 1e-5).contains(&uv[0])
+                    && (-1e-5..=1.0 + 1e-5).contains(&uv[1])));
+        }
+    }
+}
+
+#[test]
+fn test_tangents_are_opt_in() {
+    let font = load_test_font();
+    let face = fontmesh::parse_font(font.data()).unwrap();
+
+    let mesh = generate_glyph_mesh(&face, 'B', 0.2, 10).unwrap();
+    assert!(mesh.attribute(Mesh::ATTRIBUTE_TANGENT).is_none());
+
+    let style = TextMeshStyle {
+        generate_tangents: true,
+        ..default()
+    };
+    let mesh = generate_glyph_mesh_with_style(&face, 'B', &style).unwrap();
+    match mesh.attribute(Mesh::ATTRIBUTE_TANGENT) {
+        Some(VertexAttributeValues::Float32x4(tangents)) => {
+            assert_eq!(tangents.len(), mesh.count_vertices())
+        }
+        _ => panic!("Mesh should have tangents"),
+    }
+}
+
+#[test]
+fn test_font_size_scales_layout_but_not_depth() {
+    let font = load_test_font();
+    let face = fontmesh::parse_font(font.data()).unwrap();
+    let style = TextMeshStyle {
+        depth: 0.2,
+        font_size: 3.0,
+        ..default()
+    };
+
+    let small = generate_glyph_mesh(&face, 'B', 0.2, 10).unwrap();
+    let large = generate_glyph_mesh_with_style(&face, 'B', &style).unwrap();
+    let extent = |mesh: &Mesh, axis: usize| {
+        let values = positions(mesh).iter().map(|p| p[axis]);
+        values.clone().fold(f32::MIN, f32::max) - values.fold(f32::MAX, f32::min)
+    };
+    assert!((extent(&large, 0) - extent(&small, 0) * 3.0).abs() < 1e-4);
+    assert!((extent(&large, 2) - 0.2).abs() < 1e-5);
+
+    assert!(
+        (font.text_width_with_style("abc", &style) - font.text_width("abc") * 3.0).abs() < 1e-4
+    );
+
+    // Line spacing scales the distance between baselines
+    let baselines = |line_spacing: f32| {
+        let glyphs = spawn_glyphs(
+            "a\na",
+            TextMeshStyle {
+                font_size: 3.0,
+                line_spacing,
+                ..default()
+            },
+        );
+        glyphs[0].1.translation.y - glyphs[1].1.translation.y
+    };
+    let line_height = font.font_metrics().unwrap().line_height;
+    assert!((baselines(1.0) - line_height * 3.0).abs() < 1e-4);
+    assert!((baselines(1.5) - line_height * 4.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_glyph_cache_is_shared_and_evicted() {
+    let (mut app, font) = test_app();
+    for text in ["abba", "ab"] {
+        app.world_mut().spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font: font.clone(),
+                ..default()
+            },
+            ..default()
+        });
+    }
+    app.update();
+
+    // One entry per distinct glyph, regardless of how many entities use it
+    assert_eq!(app.world().resource::<GlyphMeshCache>().len(), 2);
+
+    // Removing the font drops its glyphs
+    app.world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .remove(&font);
+    app.update();
+    app.update();
+    assert!(app.world().resource::<GlyphMeshCache>().is_empty());
+
+    // The size cap evicts the least recently used glyphs
+    let (mut app, font) = test_app();
+    app.insert_resource(GlyphMeshCache::new(4));
+    app.world_mut().spawn(TextMeshBundle {
+        text_mesh: TextMesh {
+            text: "abcdefgh".to_string(),
+            font,
+            ..default()
+        },
+        ..default()
+    });
+    app.update();
+    assert!(app.world().resource::<GlyphMeshCache>().len() <= 4);
+}
+
+#[test]
+fn test_async_generation_keeps_previous_mesh() {
+    let (mut app, font) = test_app();
+    app.insert_resource(FontMeshSettings::default());
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Hi".to_string(),
+                font,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+
+    let mesh_count = |app: &mut App| {
+        let handle = app.world().get::<Mesh3d>(entity).unwrap().0.clone();
+        app.world()
+            .resource::<Assets<Mesh>>()
+            .get(&handle)
+            .map(|mesh| mesh.count_vertices())
+    };
+    let update_until = |app: &mut App, done: &dyn Fn(&mut App) -> bool| {
+        for _ in 0..100 {
+            app.update();
+            if done(app) {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("Mesh generation did not finish");
+    };
+
+    update_until(&mut app, &|app| mesh_count(app).is_some());
+    let short = mesh_count(&mut app).unwrap();
+
+    app.world_mut().get_mut::<TextMesh>(entity).unwrap().text = "Hello".to_string();
+    app.update();
+    // Until the task finishes, the previous mesh stays in place
+    assert!(mesh_count(&mut app).is_some());
+
+    update_until(&mut app, &|app| mesh_count(app) != Some(short));
+    assert!(mesh_count(&mut app).unwrap() > short);
+}
+
+#[test]
+fn test_generation_budget_defers_hidden_text() {
+    let (mut app, font) = test_app();
+    app.insert_resource(FontMeshSettings {
+        async_generation: false,
+        max_glyphs_per_frame: Some(3),
+        ..default()
+    });
+    let entities: Vec<Entity> = (0..3)
+        .map(|_| {
+            app.world_mut()
+                .spawn(TextMeshBundle {
+                    text_mesh: TextMesh {
+                        text: "ab".to_string(),
+                        font: font.clone(),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .id()
+        })
+        .collect();
+
+    // The last entity was visible, so it goes first
+    app.world_mut()
+        .get_mut::<ViewVisibility>(entities[2])
+        .unwrap()
+        .set_visible();
+
+    let generated = |app: &App| -> Vec<bool> {
+        let meshes = app.world().resource::<Assets<Mesh>>();
+        entities
+            .iter()
+            .map(|&e| meshes.contains(&app.world().get::<Mesh3d>(e).unwrap().0))
+            .collect()
+    };
+
+    // Only one text fits in 3 glyphs per frame
+    app.update();
+    assert_eq!(generated(&app), vec![false, false, true]);
+    app.update();
+    assert_eq!(generated(&app).iter().filter(|&&g| g).count(), 2);
+    app.update();
+    assert_eq!(generated(&app), vec![true, true, true]);
+}
+
+#[test]
+fn test_generation_budget_limits_applying_async_tasks() {
+    let (mut app, font) = test_app();
+    app.insert_resource(FontMeshSettings::default());
+    let entities: Vec<Entity> = (0..3)
+        .map(|_| {
+            app.world_mut()
+                .spawn(TextMeshBundle {
+                    text_mesh: TextMesh {
+                        text: "ab".to_string(),
+                        font: font.clone(),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .id()
+        })
+        .collect();
+
+    let generated = |app: &App| {
+        let meshes = app.world().resource::<Assets<Mesh>>();
+        entities
+            .iter()
+            .filter(|&&e| meshes.contains(&app.world().get::<Mesh3d>(e).unwrap().0))
+            .count()
+    };
+
+    // Start all tasks, then give them time to finish before applying any more
+    app.update();
+    app.world_mut()
+        .resource_mut::<FontMeshSettings>()
+        .max_time_per_frame = Some(Duration::ZERO);
+    let mut previous = generated(&app);
+    for _ in 0..100 {
+        std::thread::sleep(Duration::from_millis(20));
+        app.update();
+        let count = generated(&app);
+        // A spent time budget still applies one finished task per frame
+        assert!(count <= previous + 1);
+        previous = count;
+        if count == entities.len() {
+            return;
+        }
+    }
+    panic!("async tasks were never applied");
+}
+
+#[derive(Component)]
+struct Highlight;
+
+#[test]
+fn test_glyph_edits_reuse_entities() {
+    let (mut app, font) = test_app();
+    let parent = app
+        .world_mut()
+        .spawn(TextMeshGlyphsBundle {
+            text_glyphs: TextMeshGlyphs {
+                text: "abc".to_string(),
+                font,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let glyphs = |app: &mut App| {
+        let mut glyphs: Vec<(Entity, char, f32, bool)> = app
+            .world_mut()
+            .query::<(Entity, &GlyphMesh, &Transform, Has<Highlight>)>()
+            .iter(app.world())
+            .map(|(e, g, t, h)| (e, g.character, t.translation.x, h))
+            .collect();
+        glyphs.sort_by(|a, b| a.2.total_cmp(&b.2));
+        glyphs
+    };
+    let before = glyphs(&mut app);
+    app.world_mut().entity_mut(before[2].0).insert(Highlight);
+
+    // Insert a character before 'c'
+    app.world_mut()
+        .get_mut::<TextMeshGlyphs>(parent)
+        .unwrap()
+        .text = "abxc".to_string();
+    app.update();
+
+    let after = glyphs(&mut app);
+    let characters: String = after.iter().map(|g| g.1).collect();
+    assert_eq!(characters, "abxc");
+    assert_eq!(after[0].0, before[0].0);
+    assert_eq!(after[1].0, before[1].0);
+    assert_eq!(after[3].0, before[2].0, "'c' should keep its entity");
+    assert!(after[3].3, "'c' should keep user components");
+    assert!(after[3].2 > before[2].2, "'c' should move right");
+
+    // Delete the first character
+    app.world_mut()
+        .get_mut::<TextMeshGlyphs>(parent)
+        .unwrap()
+        .text = "bxc".to_string();
+    app.update();
+
+    let last = glyphs(&mut app);
+    assert_eq!(last.len(), 3);
+    assert_eq!(last[0].0, after[1].0);
+    assert_eq!(last[2].0, before[2].0);
+    assert!(app.world().get_entity(before[0].0).is_err());
+}
+
+#[test]
+fn test_rtl_glyph_edits_reuse_entities() {
+    let (mut app, font) = test_app();
+    let parent = app
+        .world_mut()
+        .spawn(TextMeshGlyphsBundle {
+            text_glyphs: TextMeshGlyphs {
+                text: "אבג".to_string(),
+                font,
+                style: TextMeshStyle {
+                    // Render Hebrew even if the font lacks it
+                    show_missing_glyphs: true,
+                    ..default()
+                },
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let glyphs = |app: &mut App| {
+        let mut glyphs: Vec<(usize, Entity)> = app
+            .world_mut()
+            .query::<(Entity, &GlyphMesh)>()
+            .iter(app.world())
+            .map(|(e, g)| (g.char_index, e))
+            .collect();
+        glyphs.sort();
+        glyphs
+    };
+    let before = glyphs(&mut app);
+
+    // Append a character, which is placed left of the others
+    app.world_mut()
+        .get_mut::<TextMeshGlyphs>(parent)
+        .unwrap()
+        .text = "אבגד".to_string();
+    app.update();
+
+    let after = glyphs(&mut app);
+    assert_eq!(after.len(), 4);
+    assert_eq!(
+        after[..3],
+        before[..],
+        "RTL glyphs should keep their entities"
+    );
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Clone)]
+struct ToonMaterial {}
+
+impl Material for ToonMaterial {}
+
+#[test]
+fn test_glyphs_with_custom_material() {
+    let (mut app, font) = test_app();
+    app.add_plugins(FontMeshGlyphsPlugin::<ToonMaterial>::default());
+    let parent = app
+        .world_mut()
+        .spawn((
+            TextMeshGlyphs {
+                text: "ab".to_string(),
+                font,
+                ..default()
+            },
+            MeshMaterial3d::<ToonMaterial>::default(),
+            Transform::default(),
+            Visibility::default(),
+        ))
+        .id();
+    app.update();
+
+    let glyphs = app
+        .world_mut()
+        .query_filtered::<&ChildOf, (With<GlyphMesh>, With<MeshMaterial3d<ToonMaterial>>)>()
+        .iter(app.world())
+        .filter(|child_of| child_of.parent() == parent)
+        .count();
+    assert_eq!(glyphs, 2, "Glyphs should copy the parent's custom material");
+}
+
+#[test]
+#[should_panic(expected = "requires FontMeshPlugin")]
+fn test_glyphs_plugin_requires_main_plugin() {
+    App::new()
+        .add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_plugins(FontMeshGlyphsPlugin::<ToonMaterial>::default());
+}
+
+#[test]
+fn test_spans_flow_on_one_line() {
+    let (mut app, font) = test_app();
+    let parent = app
+        .world_mut()
+        .spawn(TextMeshSpansBundle {
+            text_spans: TextMeshSpans {
+                spans: vec![
+                    TextMeshSpan::new("ab ", font.clone()),
+                    TextMeshSpan {
+                        font_size: Some(2.0),
+                        ..TextMeshSpan::new("cd", font).with_tag(7)
+                    },
+                ],
+                style: TextMeshStyle::default(),
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let spans = |app: &mut App| {
+        let mut spans: Vec<(Entity, SpanMesh, Handle<Mesh>)> = app
+            .world_mut()
+            .query::<(Entity, &SpanMesh, &Mesh3d)>()
+            .iter(app.world())
+            .map(|(e, s, m)| (e, s.clone(), m.0.clone()))
+            .collect();
+        spans.sort_by_key(|s| s.1.span_index);
+        spans
+    };
+    let before = spans(&mut app);
+    assert_eq!(before.len(), 2);
+    assert_eq!((before[0].1.tag, before[1].1.tag), (0, 7));
+
+    let bounds = |world: &World, handle: &Handle<Mesh>| {
+        let mesh = world.resource::<Assets<Mesh>>().get(handle).unwrap();
+        positions(mesh)
+            .iter()
+            .fold((f32::MAX, f32::MIN, f32::MIN), |b, p| {
+                (b.0.min(p[0]), b.1.max(p[0]), b.2.max(p[1]))
+            })
+    };
+    let first = bounds(app.world(), &before[0].2);
+    let second = bounds(app.world(), &before[1].2);
+    assert!(second.0 > first.1, "Second span should follow the first");
+    assert!(second.2 > first.2, "Larger span should be taller");
+
+    // A user-assigned material survives text edits
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial::default());
+    app.world_mut()
+        .entity_mut(before[1].0)
+        .insert(MeshMaterial3d(material.clone()));
+    app.world_mut()
+        .get_mut::<TextMeshSpans>(parent)
+        .unwrap()
+        .spans[1]
+        .text = "cde".to_string();
+    app.update();
+
+    let after = spans(&mut app);
+    assert_eq!(after[1].0, before[1].0);
+    assert_eq!(
+        app.world()
+            .get::<MeshMaterial3d<StandardMaterial>>(after[1].0)
+            .unwrap()
+            .0,
+        material
+    );
+}
+
+/// The test font with its character map renamed, so it has no glyph for any character
+fn font_without_cmap() -> FontMesh {
+    let mut data = load_test_font().data().to_vec();
+    let num_tables = u16::from_be_bytes([data[4], data[5]]) as usize;
+    for record in data[12..12 + num_tables * 16].chunks_exact_mut(16) {
+        if &record[..4] == b"cmap" {
+            record[..4].copy_from_slice(b"xmap");
+        }
+    }
+    FontMesh::new(data)
+}
+
+#[test]
+fn test_fallback_fonts_cover_missing_glyphs() {
+    let (mut app, fallback) = test_app();
+    let primary = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(font_without_cmap());
+
+    let mut spawn = |fallback_fonts: Vec<Handle<FontMesh>>| {
+        app.world_mut()
+            .spawn(TextMeshGlyphsBundle {
+                text_glyphs: TextMeshGlyphs {
+                    text: "ab".to_string(),
+                    font: primary.clone(),
+                    style: TextMeshStyle {
+                        fallback_fonts,
+                        ..default()
+                    },
+                },
+                ..default()
+            })
+            .id()
+    };
+    let without = spawn(Vec::new());
+    let with = spawn(vec![fallback.clone()]);
+    app.update();
+
+    let glyphs = |app: &mut App, parent: Entity| {
+        let mut glyphs: Vec<(char, f32)> = app
+            .world_mut()
+            .query::<(&GlyphMesh, &Transform, &ChildOf)>()
+            .iter(app.world())
+            .filter(|(.., child_of)| child_of.parent() == parent)
+            .map(|(glyph, transform, _)| (glyph.character, transform.translation.x))
+            .collect();
+        glyphs.sort_by(|a, b| a.1.total_cmp(&b.1));
+        glyphs
+    };
+    assert!(glyphs(&mut app, without).is_empty());
+
+    let covered = glyphs(&mut app, with);
+    assert_eq!(covered.iter().map(|g| g.0).collect::<String>(), "ab");
+    let font = app
+        .world()
+        .resource::<Assets<FontMesh>>()
+        .get(&fallback)
+        .unwrap();
+    let advance = font.char_positions("ab")[1].1;
+    assert!(
+        (covered[1].1 - covered[0].1 - advance).abs() < 1e-4,
+        "Fallback glyphs should use the fallback font's advances"
+    );
+}
+
+#[test]
+fn test_failed_fallback_fonts_are_skipped() {
+    let (mut app, font) = test_app();
+    let missing = app
+        .world()
+        .resource::<AssetServer>()
+        .load::<FontMesh>("fonts/does-not-exist.ttf");
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "ab".to_string(),
+                font,
+                style: TextMeshStyle {
+                    fallback_fonts: vec![missing],
+                    ..default()
+                },
+            },
+            ..default()
+        })
+        .id();
+
+    // The text waits while the fallback loads, then renders without it
+    let generated = |app: &App| {
+        let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+        app.world().resource::<Assets<Mesh>>().contains(handle)
+    };
+    for _ in 0..100 {
+        app.update();
+        if generated(&app) {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("Text with a failed fallback font should still be generated");
+}
+
+#[test]
+fn test_missing_glyphs_are_reported_and_optionally_shown() {
+    let (mut app, font) = test_app();
+    let mut spawn = |show_missing_glyphs: bool| {
+        app.world_mut()
+            .spawn(TextMeshGlyphsBundle {
+                text_glyphs: TextMeshGlyphs {
+                    text: "a日b日".to_string(),
+                    font: font.clone(),
+                    style: TextMeshStyle {
+                        show_missing_glyphs,
+                        ..default()
+                    },
+                },
+                ..default()
+            })
+            .id()
+    };
+    let hidden = spawn(false);
+    let shown = spawn(true);
+    app.update();
+
+    let mut reported: Vec<(Entity, Vec<char>)> = app
+        .world()
+        .resource::<Messages<MissingGlyphs>>()
+        .iter_current_update_messages()
+        .map(|message| (message.entity, message.characters.clone()))
+        .collect();
+    reported.sort();
+    let mut expected = vec![(hidden, vec!['日']), (shown, vec!['日'])];
+    expected.sort();
+    assert_eq!(reported, expected);
+
+    let glyphs = |app: &mut App, parent: Entity| {
+        let mut glyphs: Vec<(char, u16, f32)> = app
+            .world_mut()
+            .query::<(&GlyphMesh, &Transform, &ChildOf)>()
+            .iter(app.world())
+            .filter(|(.., child_of)| child_of.parent() == parent)
+            .map(|(glyph, transform, _)| (glyph.character, glyph.glyph_id, transform.translation.x))
+            .collect();
+        glyphs.sort_by(|a, b| a.2.total_cmp(&b.2));
+        glyphs
+    };
+    let hidden = glyphs(&mut app, hidden);
+    let shown = glyphs(&mut app, shown);
+
+    assert_eq!(hidden.iter().map(|g| g.0).collect::<String>(), "ab");
+    assert_eq!(shown.iter().map(|g| g.0).collect::<String>(), "a日b日");
+    assert_eq!(shown[1].1, 0, "Missing characters should use .notdef");
+    assert!(
+        shown[2].2 > hidden[1].2,
+        "Shown .notdef glyphs should take up space"
+    );
+}
+
+#[test]
+fn test_bidi_text_is_reordered() {
+    let (mut app, font) = test_app();
+    let mut spawn = |text: &str, justify: JustifyText| {
+        app.world_mut()
+            .spawn(TextMeshGlyphsBundle {
+                text_glyphs: TextMeshGlyphs {
+                    text: text.to_string(),
+                    font: font.clone(),
+                    style: TextMeshStyle {
+                        justify,
+                        // Render Hebrew even if the font lacks it
+                        show_missing_glyphs: true,
+                        ..default()
+                    },
+                },
+                ..default()
+            })
+            .id()
+    };
+    let ltr = spawn("ab אבג cd", JustifyText::Start);
+    let rtl = spawn("אב cd", JustifyText::Start);
+    app.update();
+
+    // Char indices and positions in visual order, left to right
+    let visual = |app: &mut App, parent: Entity| {
+        let mut glyphs: Vec<(usize, f32)> = app
+            .world_mut()
+            .query::<(&GlyphMesh, &Transform, &ChildOf)>()
+            .iter(app.world())
+            .filter(|(.., child_of)| child_of.parent() == parent)
+            .map(|(glyph, transform, _)| (glyph.char_index, transform.translation.x))
+            .collect();
+        glyphs.sort_by(|a, b| a.1.total_cmp(&b.1));
+        glyphs
+    };
+
+    let ltr = visual(&mut app, ltr);
+    let indices: Vec<usize> = ltr.iter().map(|g| g.0).collect();
+    assert_eq!(indices, vec![0, 1, 5, 4, 3, 7, 8]);
+
+    // A Hebrew paragraph runs right to left and starts at the right edge
+    let rtl = visual(&mut app, rtl);
+    let indices: Vec<usize> = rtl.iter().map(|g| g.0).collect();
+    assert_eq!(indices, vec![3, 4, 1, 0]);
+    assert!(rtl.iter().all(|g| g.1 < 0.0));
+}
+
+#[test]
+fn test_vertical_columns_flow_right_to_left() {
+    let style = TextMeshStyle {
+        writing_mode: WritingMode::VerticalRightToLeft,
+        ..default()
+    };
+    let glyphs = spawn_glyphs("ab\ncd", style.clone());
+    let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|ch| {
+        glyphs
+            .iter()
+            .find(|(glyph, _)| glyph.character == ch)
+            .map(|(_, transform)| transform.translation)
+            .unwrap()
+    });
+
+    assert!(b.y < a.y && d.y < c.y, "Characters should stack downwards");
+    assert!((a.x - b.x).abs() < 0.2, "A column should stay in place");
+    assert!(c.x < a.x - 0.5, "The second column should be to the left");
+    assert_eq!(glyphs[2].0.line_index, 1);
+
+    // Anchors use the bounds of the vertical block
+    let (mut app, font) = test_app();
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "ab\ncd".to_string(),
+                font,
+                style: TextMeshStyle {
+                    anchor: TextAnchor::TopRight,
+                    ..style
+                },
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let handle = app.world().get::<Mesh3d>(entity).unwrap().0.clone();
+    let mesh = app.world().resource::<Assets<Mesh>>().get(&handle).unwrap();
+    let max_x = positions(mesh)
+        .iter()
+        .map(|p| p[0])
+        .fold(f32::MIN, f32::max);
+    let max_y = positions(mesh)
+        .iter()
+        .map(|p| p[1])
+        .fold(f32::MIN, f32::max);
+    let min_y = positions(mesh)
+        .iter()
+        .map(|p| p[1])
+        .fold(f32::MAX, f32::min);
+    assert!(max_x.abs() < 1e-5 && max_y.abs() < 1e-5);
+    assert!(min_y < -1.0, "Two characters should stack below the anchor");
+}
+
+#[test]
+fn test_text_follows_arc_path() {
+    let radius = 3.0;
+    let style = TextMeshStyle {
+        anchor: TextAnchor::BottomCenter,
+        path: Some(TextMeshPath::Arc {
+            radius,
+            start_angle: std::f32::consts::PI,
+            end_angle: 0.0,
+        }),
+        ..default()
+    };
+
+    // The combined mesh is bent over the top of the circle, centered above it
+    let (mut app, font) = test_app();
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Ring".to_string(),
+                font,
+                style: style.clone(),
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let handle = app.world().get::<Mesh3d>(entity).unwrap().0.clone();
+    let mesh = app.world().resource::<Assets<Mesh>>().get(&handle).unwrap();
+    let points: Vec<Vec2> = positions(mesh)
+        .iter()
+        .map(|p| Vec2::new(p[0], p[1]))
+        .collect();
+    assert!(
+        points
+            .iter()
+            .all(|p| p.length() > radius - 1e-3 && p.y > 0.0),
+        "Text should sit on the outside of the arc"
+    );
+    let min_x = points.iter().map(|p| p.x).fold(f32::MAX, f32::min);
+    let max_x = points.iter().map(|p| p.x).fold(f32::MIN, f32::max);
+    assert!(
+        (min_x + max_x).abs() < 0.1,
+        "Text should be centered on the arc"
+    );
+
+    // Glyph children are rotated along the arc
+    let mut glyphs = spawn_glyphs("Ring", style);
+    glyphs.sort_by_key(|(glyph, _)| glyph.char_index);
+    let direction = |transform: &Transform| transform.rotation * Vec3::X;
+    assert!(direction(&glyphs[0].1).y > 0.1, "'R' should climb the arc");
+    assert!(
+        direction(&glyphs[3].1).y < -0.1,
+        "'g' should descend the arc"
+    );
+    for (_, transform) in &glyphs {
+        assert!(transform.translation.truncate().length() > radius - 1e-3);
+    }
+}
+
+#[test]
+fn test_glyph_rotation_resets_when_path_is_removed() {
+    let (mut app, font) = test_app();
+    let parent = app
+        .world_mut()
+        .spawn(TextMeshGlyphsBundle {
+            text_glyphs: TextMeshGlyphs {
+                text: "Ring".to_string(),
+                font,
+                style: TextMeshStyle {
+                    path: Some(TextMeshPath::Arc {
+                        radius: 3.0,
+                        start_angle: std::f32::consts::PI,
+                        end_angle: 0.0,
+                    }),
+                    ..default()
+                },
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let glyphs = |app: &mut App| {
+        let mut glyphs: Vec<(usize, Entity, Quat)> = app
+            .world_mut()
+            .query::<(Entity, &GlyphMesh, &Transform)>()
+            .iter(app.world())
+            .map(|(e, g, t)| (g.char_index, e, t.rotation))
+            .collect();
+        glyphs.sort_by_key(|g| g.0);
+        glyphs
+    };
+    let before = glyphs(&mut app);
+    assert!(before.iter().all(|g| g.2 != Quat::IDENTITY));
+
+    // The user turns one glyph, then the text is taken off the path
+    let turned = Quat::from_rotation_y(0.5);
+    app.world_mut()
+        .get_mut::<Transform>(before[1].1)
+        .unwrap()
+        .rotation = turned;
+    app.world_mut()
+        .get_mut::<TextMeshGlyphs>(parent)
+        .unwrap()
+        .style
+        .path = None;
+    app.update();
+
+    let after = glyphs(&mut app);
+    assert_eq!(after.len(), 4);
+    for (old, new) in before.iter().zip(&after) {
+        assert_eq!(old.1, new.1, "Glyphs should keep their entities");
+    }
+    assert_eq!(after[0].2, Quat::IDENTITY, "Path rotation should be reset");
+    assert_eq!(after[1].2, turned, "User rotation should be kept");
+    assert_eq!(after[3].2, Quat::IDENTITY);
+}
+
+#[test]
+fn test_deform_modifiers_move_vertices_and_normals() {
+    let mesh_for = |deform: Vec<TextDeform>| {
+        let (mut app, font) = test_app();
+        let entity = app
+            .world_mut()
+            .spawn(TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "WAVE".to_string(),
+                    font,
+                    style: TextMeshStyle {
+                        anchor: TextAnchor::Center,
+                        deform,
+                        ..default()
+                    },
+                },
+                ..default()
+            })
+            .id();
+        app.update();
+
+        let handle = app.world().get::<Mesh3d>(entity).unwrap().0.clone();
+        let mesh = app.world().resource::<Assets<Mesh>>().get(&handle).unwrap();
+        (positions(mesh).to_vec(), normals(mesh).to_vec())
+    };
+    let (flat, flat_normals) = mesh_for(Vec::new());
+
+    // Bending around a column pushes the edges back and turns them outwards
+    let (bent, bent_normals) = mesh_for(vec![TextDeform::Bend { radius: 1.0 }]);
+    for ((flat, bent), (flat_normal, normal)) in flat
+        .iter()
+        .zip(&bent)
+        .zip(flat_normals.iter().zip(&bent_normals))
+    {
+        assert!(bent[2] <= flat[2] + 1e-4, "Bent text should curve away");
+        assert!((Vec3::from(*normal).length() - 1.0).abs() < 1e-3);
+        if flat_normal[2] == 1.0 && flat[0].abs() > 0.5 {
+            // Front faces at the edges face sideways
+            assert!(normal[0] * flat[0] > 0.0);
+        }
+    }
+
+    // A wave only moves vertices vertically, tilting front normals in-plane only
+    let wave = TextDeform::Wave {
+        amplitude: 0.2,
+        wavelength: 1.0,
+        phase: 0.0,
+    };
+    let (waved, waved_normals) = mesh_for(vec![wave]);
+    let mut moved = false;
+    for ((flat, waved), (flat_normal, normal)) in flat
+        .iter()
+        .zip(&waved)
+        .zip(flat_normals.iter().zip(&waved_normals))
+    {
+        assert_eq!((flat[0], flat[2]), (waved[0], waved[2]));
+        moved |= (flat[1] - waved[1]).abs() > 0.1;
+        if flat_normal[2] == 1.0 {
+            assert_eq!(normal, flat_normal, "Front faces stay flat");
+        }
+    }
+    assert!(moved, "Vertices should follow the wave");
+
+    // A taper scales heights linearly from the start to the end of the text
+    let (tapered, _) = mesh_for(vec![TextDeform::Taper {
+        start: 1.0,
+        end: 0.5,
+    }]);
+    let min_x = flat.iter().map(|p| p[0]).fold(f32::MAX, f32::min);
+    let max_x = flat.iter().map(|p| p[0]).fold(f32::MIN, f32::max);
+    let height_ratio = |in_region: &dyn Fn(f32) -> bool| {
+        let extent = |mesh: &[[f32; 3]]| {
+            let ys = flat
+                .iter()
+                .zip(mesh)
+                .filter(|(p, _)| in_region(p[0]))
+                .map(|(_, p)| p[1]);
+            ys.clone().fold(f32::MIN, f32::max) - ys.fold(f32::MAX, f32::min)
+        };
+        extent(&tapered) / extent(&flat)
+    };
+    let edge = (max_x - min_x) * 0.1;
+    let start_ratio = height_ratio(&|x| x < min_x + edge);
+    let end_ratio = height_ratio(&|x| x > max_x - edge);
+    assert!(start_ratio > 0.9, "Start keeps its height: {start_ratio}");
+    assert!(
+        (0.45..0.6).contains(&end_ratio),
+        "End is half as tall: {end_ratio}"
+    );
+
+    // A twist turns the front face about the x axis, and its normals stay
+    // perpendicular to the twisted surface: (-angle * y, -sin, cos)
+    let angle = 0.5;
+    let (twisted, twisted_normals) = mesh_for(vec![TextDeform::Twist { angle }]);
+    for ((flat, twisted), (flat_normal, normal)) in flat
+        .iter()
+        .zip(&twisted)
+        .zip(flat_normals.iter().zip(&twisted_normals))
+    {
+        assert_eq!(flat[0], twisted[0], "A twist keeps x");
+        if flat_normal[2] == 1.0 {
+            let (sin, cos) = (angle * flat[0]).sin_cos();
+            let expected = Vec3::new(-angle * flat[1], -sin, cos).normalize();
+            assert!(
+                Vec3::from(*normal).distance(expected) < 1e-2,
+                "Front normal at x = {} should be {expected}, got {normal:?}",
+                flat[0]
+            );
+        }
+    }
+}